@@ -0,0 +1,6 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Input selection for transaction building.
+
+pub mod new;
@@ -0,0 +1,98 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A branch-and-bound search for an input subset whose summed amount lands in `[target, target +
+//! cost_of_change]`. Only a subset that hits `target` exactly avoids a remainder; any surplus inside the window
+//! still goes into a remainder output or into the transitioned chain output.
+
+use crate::secret::types::InputSigningData;
+
+/// The maximum number of inputs a transaction can have, mirroring `bee_block::input::INPUT_COUNT_MAX`. Used here to
+/// cap how deep a single branch can select, independently of [`BranchAndBoundSearch::MAX_EXPLORED_NODES`].
+const INPUT_COUNT_MAX: usize = 128;
+
+/// Depth-first search over `candidates` (assumed sorted descending by amount) for the first subset, preferring
+/// fewer inputs, whose summed amount falls in `[target, target + cost_of_change]`. Returns `None` when no such
+/// subset exists, in which case the caller should fall back to a largest-first selection that creates a remainder.
+pub(super) fn search<'a>(
+    candidates: &[&'a InputSigningData],
+    target: u64,
+    cost_of_change: u64,
+) -> Option<Vec<&'a InputSigningData>> {
+    BranchAndBoundSearch::new(candidates, target, target.saturating_add(cost_of_change)).run()
+}
+
+/// Bounds the search to [`INPUT_COUNT_MAX`] selected inputs and [`Self::MAX_EXPLORED_NODES`] explored branches, so
+/// a wallet with a large UTXO set can't turn this into an exponential-time search.
+struct BranchAndBoundSearch<'a> {
+    candidates: &'a [&'a InputSigningData],
+    // suffix_sums[i] is the sum of candidates[i..]'s amounts, used to prune branches that can never reach `target`.
+    suffix_sums: Vec<u64>,
+    target: u64,
+    upper_bound: u64,
+    explored: u32,
+    best: Option<Vec<&'a InputSigningData>>,
+}
+
+impl<'a> BranchAndBoundSearch<'a> {
+    const MAX_EXPLORED_NODES: u32 = 10_000;
+
+    fn new(candidates: &'a [&'a InputSigningData], target: u64, upper_bound: u64) -> Self {
+        let mut suffix_sums = vec![0u64; candidates.len() + 1];
+        for (i, input) in candidates.iter().enumerate().rev() {
+            suffix_sums[i] = suffix_sums[i + 1] + input.output().amount();
+        }
+        Self {
+            candidates,
+            suffix_sums,
+            target,
+            upper_bound,
+            explored: 0,
+            best: None,
+        }
+    }
+
+    /// Runs the search and returns the selected candidates, if a qualifying subset was found within the explored-
+    /// node and depth bounds. Callers should fall back to a largest-first selection when this returns `None`.
+    fn run(mut self) -> Option<Vec<&'a InputSigningData>> {
+        let mut current = Vec::new();
+        self.search_from(0, 0, &mut current);
+        self.best
+    }
+
+    fn search_from(&mut self, index: usize, running_total: u64, current: &mut Vec<&'a InputSigningData>) {
+        if self.explored >= Self::MAX_EXPLORED_NODES || current.len() > INPUT_COUNT_MAX {
+            return;
+        }
+        self.explored += 1;
+
+        if running_total >= self.target && running_total <= self.upper_bound {
+            if self.best.as_ref().map_or(true, |b| current.len() < b.len()) {
+                self.best = Some(current.clone());
+            }
+            // An exact-enough subset was already found; no need to keep branching deeper from here since every
+            // descendant would only add more inputs than this one.
+            return;
+        }
+
+        if index == self.candidates.len() {
+            return;
+        }
+
+        // Prune: even if every remaining candidate were included, the target couldn't be reached.
+        if running_total + self.suffix_sums[index] < self.target {
+            return;
+        }
+
+        // Branch 1: include candidates[index], provided it doesn't overshoot the window.
+        let amount = self.candidates[index].output().amount();
+        if running_total + amount <= self.upper_bound {
+            current.push(self.candidates[index]);
+            self.search_from(index + 1, running_total + amount, current);
+            current.pop();
+        }
+
+        // Branch 2: exclude candidates[index].
+        self.search_from(index + 1, running_total, current);
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::block::output::{AliasId, ChainId, FoundryId, NftId, TokenId};
+
+/// Explicitly burns chain outputs, mana, or native tokens instead of letting [`InputSelection`](super::
+/// InputSelection) return them as a remainder, so the corresponding id/amount goes out of existence rather than
+/// being carried over into a new output.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Burn {
+    pub(crate) aliases: HashSet<AliasId>,
+    pub(crate) nfts: HashSet<NftId>,
+    pub(crate) foundries: HashSet<FoundryId>,
+    pub(crate) mana: bool,
+    pub(crate) native_tokens: HashMap<TokenId, u64>,
+}
+
+impl Burn {
+    /// Creates an empty [`Burn`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an alias to the set of chains to burn.
+    pub fn add_alias(mut self, alias_id: AliasId) -> Self {
+        self.aliases.insert(alias_id);
+        self
+    }
+
+    /// Adds an NFT to the set of chains to burn.
+    pub fn add_nft(mut self, nft_id: NftId) -> Self {
+        self.nfts.insert(nft_id);
+        self
+    }
+
+    /// Adds a foundry to the set of chains to burn.
+    pub fn add_foundry(mut self, foundry_id: FoundryId) -> Self {
+        self.foundries.insert(foundry_id);
+        self
+    }
+
+    /// Sets whether surplus mana should be discarded instead of generating a mana remainder. When set, the
+    /// selection allows available mana to exceed required mana without error.
+    pub fn set_mana(mut self, burn_mana: bool) -> Self {
+        self.mana = burn_mana;
+        self
+    }
+
+    /// Burns `amount` of the given native token, subtracted from the input side before the remainder for that
+    /// token id is computed.
+    pub fn add_native_token(mut self, token_id: TokenId, amount: u64) -> Self {
+        *self.native_tokens.entry(token_id).or_default() += amount;
+        self
+    }
+
+    /// Whether `chain_id` is one of the chains this [`Burn`] destroys, used by [`InputSelection::select`](super::
+    /// InputSelection::select) to drop any output left in place for a burned chain.
+    pub(super) fn chain_id_burned(&self, chain_id: ChainId) -> bool {
+        match chain_id {
+            ChainId::Alias(alias_id) => self.aliases.contains(&alias_id),
+            ChainId::Nft(nft_id) => self.nfts.contains(&nft_id),
+            ChainId::Foundry(foundry_id) => self.foundries.contains(&foundry_id),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fixed-point mana decay and generation math, mirroring the reference algorithm from the mana decay TIP: stored
+//! mana is decayed per elapsed epoch using a lookup table of decay factors, and potential mana is generated from an
+//! output's base token amount over the slots it has been held before being decayed the same way.
+
+use std::collections::HashMap;
+
+use super::Burn;
+use crate::{
+    block::{
+        output::{Output, TokenId},
+        protocol::ProtocolParameters,
+    },
+    secret::types::InputSigningData,
+    Error, Result,
+};
+
+/// Decay `mana` for the given number of whole `epochs_elapsed`, using the fixed-point decay factor table from
+/// `protocol_parameters`. Epoch counts beyond the table are folded in first via `decay_factor_epochs_sum`, the
+/// product of applying the last tabulated factor `decay_factors.len()` times.
+pub fn decay_mana(mana: u64, protocol_parameters: &ProtocolParameters, epochs_elapsed: u32) -> u64 {
+    let parameters = protocol_parameters.mana_parameters();
+
+    if mana == 0 || epochs_elapsed == 0 || parameters.decay_factors.is_empty() {
+        return mana;
+    }
+
+    let table_len = parameters.decay_factors.len() as u32;
+    let mut value = mana;
+
+    // Whole multiples of the table length can be applied in one shot via the precomputed sum factor.
+    for _ in 0..(epochs_elapsed / table_len) {
+        value = fixed_point_mul(value, parameters.decay_factor_epochs_sum, parameters.decay_factors_exponent);
+    }
+
+    let remainder = epochs_elapsed % table_len;
+    if remainder > 0 {
+        let factor = parameters.decay_factors[(remainder - 1) as usize];
+        value = fixed_point_mul(value, factor, parameters.decay_factors_exponent);
+    }
+
+    value
+}
+
+/// Mana generated by holding `amount` base tokens for `elapsed_slots`, then decayed up to the transaction's target
+/// slot the same way stored mana is.
+pub fn potential_mana(amount: u64, protocol_parameters: &ProtocolParameters, elapsed_slots: u64) -> u64 {
+    let parameters = protocol_parameters.mana_parameters();
+
+    let generated = fixed_point_mul(
+        amount.saturating_mul(elapsed_slots),
+        parameters.generation_rate as u64,
+        parameters.generation_rate_exponent,
+    );
+
+    let epochs_elapsed = (elapsed_slots / protocol_parameters.slots_per_epoch() as u64) as u32;
+    decay_mana(generated, protocol_parameters, epochs_elapsed)
+}
+
+/// Multiplies `value` by a fixed-point `factor` scaled by `2^exponent`, shifting the result back down.
+fn fixed_point_mul(value: u64, factor: u64, exponent: u32) -> u64 {
+    (((value as u128) * (factor as u128)) >> exponent) as u64
+}
+
+/// `input`'s mana contribution at `target_slot`: its stored mana decayed from `creation_slot`, plus potential mana
+/// generated over the slots it's been held, both decayed the same way.
+pub(super) fn input_mana(input: &InputSigningData, protocol_parameters: &ProtocolParameters, target_slot: u64) -> u64 {
+    let elapsed_slots = target_slot.saturating_sub(input.creation_slot());
+    let stored = decay_mana(
+        input.output().mana(),
+        protocol_parameters,
+        (elapsed_slots / protocol_parameters.slots_per_epoch() as u64) as u32,
+    );
+    stored.saturating_add(potential_mana(input.output().amount(), protocol_parameters, elapsed_slots))
+}
+
+/// Total available mana across `selected_inputs`: stored mana decayed to `target_slot`, plus potential mana
+/// generated since each input's creation slot.
+pub(super) fn available_mana(
+    selected_inputs: &[InputSigningData],
+    protocol_parameters: &ProtocolParameters,
+    target_slot: u64,
+) -> u64 {
+    selected_inputs
+        .iter()
+        .map(|input| input_mana(input, protocol_parameters, target_slot))
+        .sum()
+}
+
+/// Given the already-selected inputs and the base-token-balanced outputs, folds in the mana and base-token
+/// remainder outputs. `selected_amount`/`required_amount` are the base token totals already computed by
+/// [`InputSelection::select`](super::InputSelection::select).
+pub(super) fn with_remainders(
+    selected_inputs: &[InputSigningData],
+    mut outputs: Vec<Output>,
+    selected_amount: u64,
+    required_amount: u64,
+    protocol_parameters: &ProtocolParameters,
+    target_slot: u64,
+    burn: Option<&Burn>,
+) -> Result<Vec<Output>> {
+    let required_mana: u64 = outputs.iter().map(|output| output.mana()).sum();
+    let available = available_mana(selected_inputs, protocol_parameters, target_slot);
+    let burning_mana = burn.is_some_and(|burn| burn.mana);
+
+    if available < required_mana {
+        return Err(Error::NotEnoughBalance {
+            found: available,
+            required: required_mana,
+        });
+    }
+
+    let mana_surplus = available - required_mana;
+    let amount_surplus = selected_amount - required_amount;
+
+    if mana_surplus > 0 && !burning_mana {
+        // Prefer folding the surplus into a chain output that's already being transitioned in place, rather than
+        // spawning a dedicated basic remainder purely to carry mana.
+        if let Some(transitioned) = outputs.iter_mut().find(|output| output.chain_id().is_some()) {
+            *transitioned = transitioned.clone().with_mana(transitioned.mana() + mana_surplus);
+        } else if amount_surplus == 0 {
+            // No amount surplus and no chain output to attach to: mana would otherwise be silently dropped.
+            return Err(Error::NotEnoughBalance {
+                found: available,
+                required: required_mana,
+            });
+        }
+    }
+
+    if amount_surplus > 0 {
+        if let Some(transitioned) = outputs.iter_mut().find(|output| output.chain_id().is_some()) {
+            *transitioned = transitioned.clone().with_amount(transitioned.amount() + amount_surplus);
+        } else {
+            outputs.push(remainder_address(selected_inputs).map(|address| {
+                Output::basic_with_amount_and_mana(amount_surplus, if burning_mana { 0 } else { mana_surplus }, address)
+            })?);
+        }
+    }
+
+    with_native_token_remainder(selected_inputs, outputs, burn)
+}
+
+/// Subtracts any burned native token amounts from the input side, then appends a remainder output for whatever
+/// native token surplus is left per token id.
+fn with_native_token_remainder(
+    selected_inputs: &[InputSigningData],
+    mut outputs: Vec<Output>,
+    burn: Option<&Burn>,
+) -> Result<Vec<Output>> {
+    let burned = burn.map(|burn| &burn.native_tokens);
+
+    let mut balances: HashMap<TokenId, u64> = HashMap::new();
+    for input in selected_inputs {
+        for native_token in input.output().native_tokens() {
+            *balances.entry(*native_token.token_id()).or_default() += native_token.amount();
+        }
+    }
+    for output in &outputs {
+        for native_token in output.native_tokens() {
+            let balance = balances.entry(*native_token.token_id()).or_default();
+            *balance = balance.checked_sub(native_token.amount()).ok_or(Error::NotEnoughBalance {
+                found: *balance,
+                required: native_token.amount(),
+            })?;
+        }
+    }
+
+    if let Some(burned) = burned {
+        for (token_id, burn_amount) in burned {
+            let balance = balances.entry(*token_id).or_default();
+            *balance = balance
+                .checked_sub(*burn_amount)
+                .ok_or(Error::NotEnoughBalance {
+                    found: *balance,
+                    required: *burn_amount,
+                })?;
+        }
+    }
+
+    let remainder: Vec<_> = balances.into_iter().filter(|(_, amount)| *amount > 0).collect();
+    if !remainder.is_empty() {
+        let address = remainder_address(selected_inputs)?;
+        for (token_id, amount) in remainder {
+            outputs.push(Output::basic_with_native_token(token_id, amount, address.clone()));
+        }
+    }
+
+    Ok(outputs)
+}
+
+fn remainder_address(selected_inputs: &[InputSigningData]) -> Result<crate::block::address::Address> {
+    selected_inputs
+        .first()
+        .map(|input| input.output().address().clone())
+        .ok_or(Error::NoAvailableInputsProvided)
+}
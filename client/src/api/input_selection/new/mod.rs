@@ -0,0 +1,252 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Selects a set of inputs that covers the base token amount, native tokens, mana and chain-output requirements
+//! implied by a set of outputs, generating remainder outputs for any surplus.
+
+mod branch_and_bound;
+mod burn;
+mod mana;
+mod semantic;
+mod strategy;
+
+pub use burn::Burn;
+pub use mana::{decay_mana, potential_mana};
+pub use semantic::{verify_semantic, TransactionFailureReason};
+pub use strategy::SelectionStrategy;
+
+use crate::{
+    block::{
+        address::Address,
+        output::Output,
+        protocol::ProtocolParameters,
+    },
+    secret::types::InputSigningData,
+    Error, Result,
+};
+
+/// The individual conservation requirements a selection has to satisfy. [`InputSelection::select`] and
+/// [`verify_semantic`](super::new::verify_semantic) check these conditions directly rather than through this type;
+/// `verify_semantic` reports a failed one as a `TransactionFailureReason`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requirement {
+    /// The base token amount of the inputs has to match that of the outputs.
+    Amount,
+    /// Every native token id present in the outputs has to be covered by the inputs.
+    NativeTokens,
+    /// Mana consumed by the outputs has to be covered by the inputs' stored and potential mana, decayed up to the
+    /// transaction's target slot. Only the outputs' own mana counts as consumed; mana allotments are not counted.
+    Mana,
+}
+
+/// The result of a successful [`InputSelection::select`] run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selected {
+    /// The inputs the selection settled on.
+    pub inputs: Vec<InputSigningData>,
+    /// The outputs the selection settled on, including any remainders it had to generate.
+    pub outputs: Vec<Output>,
+}
+
+/// Selects inputs from a candidate set to cover a set of required outputs, optionally [burning](Burn) rather than
+/// returning any surplus chain output, mana, or native token.
+pub struct InputSelection {
+    available_inputs: Vec<InputSigningData>,
+    outputs: Vec<Output>,
+    protocol_parameters: ProtocolParameters,
+    burn: Option<Burn>,
+    target_slot: Option<u64>,
+    strategy: SelectionStrategy,
+}
+
+impl InputSelection {
+    /// Creates a new [`InputSelection`] over `available_inputs`, required to cover `outputs`.
+    pub fn new(
+        available_inputs: Vec<InputSigningData>,
+        outputs: Vec<Output>,
+        protocol_parameters: ProtocolParameters,
+    ) -> Self {
+        Self {
+            available_inputs,
+            outputs,
+            protocol_parameters,
+            burn: None,
+            target_slot: None,
+            strategy: SelectionStrategy::default(),
+        }
+    }
+
+    /// Burns the given chains/mana/native tokens instead of returning them as a remainder.
+    pub fn with_burn(mut self, burn: Burn) -> Self {
+        self.burn = Some(burn);
+        self
+    }
+
+    /// Sets the heuristic used to order candidate inputs whenever more than one could satisfy an outstanding
+    /// requirement. Defaults to [`SelectionStrategy::PreferBasic`].
+    pub fn with_selection_strategy(mut self, strategy: SelectionStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the slot the resulting transaction will be issued in. Stored and potential mana on the selected inputs
+    /// is decayed up to this slot; it defaults to the latest creation slot among the available inputs when unset.
+    pub fn with_target_slot(mut self, target_slot: u64) -> Self {
+        self.target_slot = Some(target_slot);
+        self
+    }
+
+    fn target_slot(&self) -> u64 {
+        self.target_slot.unwrap_or_else(|| {
+            self.available_inputs
+                .iter()
+                .map(InputSigningData::creation_slot)
+                .max()
+                .unwrap_or(0)
+        })
+    }
+
+    /// Runs the selection, returning the inputs and outputs (including any generated remainders) for a
+    /// semantically valid transaction.
+    pub fn select(mut self) -> Result<Selected> {
+        let target_slot = self.target_slot();
+
+        if let Some(burn) = &self.burn {
+            // A burned chain output must actually be among the available inputs; otherwise there's nothing to
+            // destroy and the caller most likely passed the wrong id.
+            for alias_id in &burn.aliases {
+                if !self.available_inputs.iter().any(|input| input.output().alias_id() == Some(*alias_id)) {
+                    return Err(Error::BurnedChainNotAvailable(alias_id.to_string()));
+                }
+            }
+            for nft_id in &burn.nfts {
+                if !self.available_inputs.iter().any(|input| input.output().nft_id() == Some(*nft_id)) {
+                    return Err(Error::BurnedChainNotAvailable(nft_id.to_string()));
+                }
+            }
+            for foundry_id in &burn.foundries {
+                if !self
+                    .available_inputs
+                    .iter()
+                    .any(|input| input.output().foundry_id() == Some(*foundry_id))
+                {
+                    return Err(Error::BurnedChainNotAvailable(foundry_id.to_string()));
+                }
+            }
+
+            // Drop any output the caller left in place for a chain that's being burned; its base tokens/mana/native
+            // tokens flow back into the balance as a surplus instead of being carried over.
+            self.outputs
+                .retain(|output| output.chain_id().map_or(true, |chain_id| !burn.chain_id_burned(chain_id)));
+        }
+
+        let mut selected: Vec<InputSigningData> = Vec::new();
+        let mut selected_amount = 0u64;
+        let required_amount: u64 = self.outputs.iter().map(Output::amount).sum();
+
+        // Inputs that are already required by the outputs (chain transitions) are always selected first; only
+        // once those are accounted for do we pull in additional basic inputs purely for their amount/mana.
+        for input in &self.available_inputs {
+            if output_is_required(input.output(), &self.outputs) {
+                selected_amount += input.output().amount();
+                selected.push(input.clone());
+            }
+        }
+
+        let mut candidates: Vec<&InputSigningData> = self
+            .available_inputs
+            .iter()
+            .filter(|input| !selected.iter().any(|s| s.output_id() == input.output_id()))
+            .collect();
+
+        if self.strategy == SelectionStrategy::BranchAndBound && selected_amount < required_amount {
+            let outstanding = required_amount - selected_amount;
+            let cost_of_change = selected
+                .first()
+                .or_else(|| candidates.first().copied())
+                .map(|input| cost_of_change(&self.protocol_parameters, input.output().address()))
+                .unwrap_or(0);
+
+            candidates.sort_by_key(|input| std::cmp::Reverse(input.output().amount()));
+            if let Some(subset) = branch_and_bound::search(&candidates, outstanding, cost_of_change) {
+                for input in subset {
+                    selected_amount += input.output().amount();
+                    selected.push(input.clone());
+                    candidates.retain(|c| c.output_id() != input.output_id());
+                }
+            }
+        }
+
+        self.strategy.order(&mut candidates);
+
+        for input in candidates {
+            if selected_amount >= required_amount {
+                break;
+            }
+            selected_amount += input.output().amount();
+            selected.push(input.clone());
+        }
+
+        if selected_amount < required_amount {
+            return Err(Error::NotEnoughBalance {
+                found: selected_amount,
+                required: required_amount,
+            });
+        }
+
+        // The inputs selected above were chosen purely to cover the base token amount; if they don't also carry
+        // enough mana, pull in further available inputs, ranked by mana contribution, until the mana requirement
+        // is covered too.
+        let required_mana: u64 = self.outputs.iter().map(Output::mana).sum();
+        let mut available_mana = mana::available_mana(&selected, &self.protocol_parameters, target_slot);
+
+        if available_mana < required_mana {
+            let mut mana_candidates: Vec<&InputSigningData> = self
+                .available_inputs
+                .iter()
+                .filter(|input| !selected.iter().any(|s| s.output_id() == input.output_id()))
+                .collect();
+            mana_candidates
+                .sort_by_key(|input| std::cmp::Reverse(mana::input_mana(input, &self.protocol_parameters, target_slot)));
+
+            for input in mana_candidates {
+                if available_mana >= required_mana {
+                    break;
+                }
+                available_mana += mana::input_mana(input, &self.protocol_parameters, target_slot);
+                selected_amount += input.output().amount();
+                selected.push(input.clone());
+            }
+        }
+
+        let outputs = mana::with_remainders(
+            &selected,
+            self.outputs,
+            selected_amount,
+            required_amount,
+            &self.protocol_parameters,
+            target_slot,
+            self.burn.as_ref(),
+        )?;
+
+        Ok(Selected {
+            inputs: selected,
+            outputs,
+        })
+    }
+}
+
+/// Whether `output` is already referenced by one of `outputs` as a chain transition (i.e. its alias/nft/foundry id
+/// reappears), in which case it must be selected regardless of whether it's needed for its amount.
+fn output_is_required(output: &Output, outputs: &[Output]) -> bool {
+    match output.chain_id() {
+        Some(chain_id) => outputs.iter().any(|o| o.chain_id() == Some(chain_id)),
+        None => false,
+    }
+}
+
+/// The minimal storage deposit a basic remainder output locked to `address` would need, i.e. the width of the
+/// branch-and-bound search's no-remainder window.
+fn cost_of_change(protocol_parameters: &ProtocolParameters, address: &Address) -> u64 {
+    Output::basic_with_amount_and_mana(0, 0, address.clone()).rent_cost(protocol_parameters.rent_structure())
+}
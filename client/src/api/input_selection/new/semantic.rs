@@ -0,0 +1,150 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Recomputes the conservation invariants [`InputSelection::select`](super::InputSelection::select) is supposed to
+//! guarantee, so a hand-assembled input/output set built outside of [`InputSelection`] can be checked too.
+
+use std::collections::HashMap;
+
+use super::{mana, Selected};
+use crate::{
+    block::{
+        address::Address,
+        output::{unlock_condition::UnlockCondition, Output, TokenId},
+        protocol::ProtocolParameters,
+    },
+    secret::types::InputSigningData,
+};
+
+/// Why a set of inputs/outputs would fail semantic validation, mirroring the reasons a node would reject the
+/// resulting transaction for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionFailureReason {
+    /// The base token amount of the inputs doesn't match that of the outputs.
+    InputOutputBaseTokenMismatch,
+    /// At least one native token id's input and output amounts don't match.
+    NativeTokenSumUnbalanced,
+    /// Available mana (stored, decayed, plus potential) doesn't cover the mana required by the outputs.
+    ManaSumUnbalanced,
+    /// An output's unlock conditions can't be satisfied by any of the selected inputs.
+    UnlockConditionsNotMet,
+}
+
+/// Recomputes the conservation invariants that `inputs`/`outputs` are supposed to satisfy, independent of whether
+/// they were produced by [`InputSelection::select`](super::InputSelection::select).
+pub fn verify_semantic(
+    inputs: &[InputSigningData],
+    outputs: &[Output],
+    protocol_parameters: &ProtocolParameters,
+) -> Result<(), TransactionFailureReason> {
+    verify_base_token_balance(inputs, outputs)?;
+    verify_native_token_balance(inputs, outputs)?;
+    verify_mana_balance(inputs, outputs, protocol_parameters)?;
+    verify_unlock_conditions(inputs, outputs)?;
+
+    Ok(())
+}
+
+fn verify_base_token_balance(inputs: &[InputSigningData], outputs: &[Output]) -> Result<(), TransactionFailureReason> {
+    let input_amount: u64 = inputs.iter().map(|input| input.output().amount()).sum();
+    let output_amount: u64 = outputs.iter().map(Output::amount).sum();
+
+    if input_amount != output_amount {
+        return Err(TransactionFailureReason::InputOutputBaseTokenMismatch);
+    }
+
+    Ok(())
+}
+
+fn verify_native_token_balance(inputs: &[InputSigningData], outputs: &[Output]) -> Result<(), TransactionFailureReason> {
+    let mut balances: HashMap<TokenId, i128> = HashMap::new();
+
+    for input in inputs {
+        for native_token in input.output().native_tokens() {
+            *balances.entry(*native_token.token_id()).or_default() += native_token.amount() as i128;
+        }
+    }
+    for output in outputs {
+        for native_token in output.native_tokens() {
+            *balances.entry(*native_token.token_id()).or_default() -= native_token.amount() as i128;
+        }
+    }
+
+    if balances.values().any(|balance| *balance != 0) {
+        return Err(TransactionFailureReason::NativeTokenSumUnbalanced);
+    }
+
+    Ok(())
+}
+
+fn verify_mana_balance(
+    inputs: &[InputSigningData],
+    outputs: &[Output],
+    protocol_parameters: &ProtocolParameters,
+) -> Result<(), TransactionFailureReason> {
+    // Mana was already decayed to a target slot by the caller when building these inputs; re-deriving that target
+    // slot here just means taking the latest creation slot among them, same as `InputSelection` defaults to.
+    let target_slot = inputs.iter().map(InputSigningData::creation_slot).max().unwrap_or(0);
+
+    let available: u64 = inputs
+        .iter()
+        .map(|input| {
+            let elapsed_slots = target_slot.saturating_sub(input.creation_slot());
+            let stored = mana::decay_mana(
+                input.output().mana(),
+                protocol_parameters,
+                (elapsed_slots / protocol_parameters.slots_per_epoch() as u64) as u32,
+            );
+            stored.saturating_add(mana::potential_mana(input.output().amount(), protocol_parameters, elapsed_slots))
+        })
+        .sum();
+    let required: u64 = outputs.iter().map(Output::mana).sum();
+
+    if available < required {
+        return Err(TransactionFailureReason::ManaSumUnbalanced);
+    }
+
+    Ok(())
+}
+
+/// Checks that every chain address an output's unlock conditions reference (state controller/governor/immutable
+/// alias address) is actually backed by ownership of that chain among `inputs`, i.e. the alias/NFT is itself being
+/// consumed in this transaction. An output locked to a chain address nobody here owns could never be unlocked.
+fn verify_unlock_conditions(inputs: &[InputSigningData], outputs: &[Output]) -> Result<(), TransactionFailureReason> {
+    for output in outputs {
+        for uc in output.unlock_conditions().iter() {
+            let chain_address = match uc {
+                UnlockCondition::StateControllerAddress(uc) => Some(uc.address()),
+                UnlockCondition::GovernorAddress(uc) => Some(uc.address()),
+                UnlockCondition::ImmutableAliasAddress(uc) => Some(uc.address()),
+                _ => None,
+            };
+
+            let owned = match chain_address {
+                Some(Address::Alias(alias_address)) => {
+                    inputs.iter().any(|input| input.output().alias_id() == Some(*alias_address.alias_id()))
+                }
+                Some(Address::Nft(nft_address)) => {
+                    inputs.iter().any(|input| input.output().nft_id() == Some(*nft_address.nft_id()))
+                }
+                // Ed25519 addresses and unlock conditions that don't reference a chain at all impose no
+                // input-side ownership requirement here.
+                Some(_) | None => true,
+            };
+
+            if !owned {
+                return Err(TransactionFailureReason::UnlockConditionsNotMet);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl Selected {
+    /// Re-checks the conservation invariants of this selection's `inputs`/`outputs`; useful after manual edits to
+    /// either before finalizing a transaction.
+    pub fn validate(&self, protocol_parameters: &ProtocolParameters) -> Result<(), TransactionFailureReason> {
+        verify_semantic(&self.inputs, &self.outputs, protocol_parameters)
+    }
+}
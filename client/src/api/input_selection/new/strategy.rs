@@ -0,0 +1,53 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Strategies for choosing among several equally-valid candidate inputs, consulted by [`InputSelection::select`]
+//! when it picks inputs to cover the outstanding base token amount. Inputs pulled in for mana are ranked by their
+//! mana contribution instead, and no strategy applies to native tokens.
+
+use crate::secret::types::InputSigningData;
+
+/// Which heuristic [`InputSelection::select`](super::InputSelection::select) uses when several available inputs
+/// could equally satisfy the outstanding requirements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Consume basic outputs before chain (NFT/alias/foundry) outputs, only falling back to the latter once the
+    /// former are exhausted. This is the default, and what the selection did before strategies existed.
+    PreferBasic,
+    /// Consume chain outputs first, so their amount and mana can be rolled straight into the transitioned output
+    /// instead of spawning a separate remainder.
+    PreferChainTransition,
+    /// Largest amount first, so the outstanding base token amount is covered by as few inputs as possible,
+    /// regardless of output kind.
+    MinimizeInputs,
+    /// Branch-and-bound search for an input subset whose amount lands in `[target, target + cost_of_change]`,
+    /// falling back to a largest-first selection (and a remainder output) when no such subset exists. Only an exact
+    /// match avoids a remainder; a surplus inside the window still goes into a remainder or the transitioned output.
+    BranchAndBound,
+}
+
+impl Default for SelectionStrategy {
+    fn default() -> Self {
+        Self::PreferBasic
+    }
+}
+
+impl SelectionStrategy {
+    /// Orders `candidates` (available inputs not yet selected, largest requirement-coverage problem first) so that
+    /// the ones this strategy prefers to consume come first.
+    pub(super) fn order<'a>(&self, candidates: &mut Vec<&'a InputSigningData>) {
+        match self {
+            Self::PreferBasic => {
+                candidates.sort_by_key(|input| input.output().chain_id().is_some());
+            }
+            Self::PreferChainTransition => {
+                candidates.sort_by_key(|input| input.output().chain_id().is_none());
+            }
+            Self::MinimizeInputs | Self::BranchAndBound => {
+                // Largest amount first covers the outstanding requirement in as few inputs as possible; for
+                // `BranchAndBound` this is also the fallback ordering used when no in-window subset exists.
+                candidates.sort_by_key(|input| std::cmp::Reverse(input.output().amount()));
+            }
+        }
+    }
+}
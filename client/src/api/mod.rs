@@ -0,0 +1,6 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Block and transaction building helpers.
+
+pub mod input_selection;
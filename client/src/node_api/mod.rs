@@ -0,0 +1,6 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Node REST API endpoints, grouped by plugin.
+
+pub mod participation;
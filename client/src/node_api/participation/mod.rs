@@ -0,0 +1,8 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Voting and staking via a node's `participation` plugin.
+
+pub mod rewards;
+pub mod routes;
+pub mod types;
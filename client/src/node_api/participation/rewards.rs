@@ -0,0 +1,132 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reconstructs the voting power and staking rewards an output accrued over the milestones it stayed unspent and
+//! carried a valid participation for an event.
+
+use serde::Deserialize;
+
+use crate::node_api::participation::types::{EventInfo, EventPayload, Participation, Participations};
+
+/// The bounds within which an output existed, plus the participation (if any) it carried, needed to tally its
+/// [`voting_power`] for one event.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputParticipationRecord {
+    /// The amount of tokens held by the output.
+    pub amount: u64,
+    /// The milestone index at which the output was created.
+    pub created_milestone_index: u32,
+    /// The milestone index at which the output was spent, or `None` if it's still unspent.
+    pub spent_milestone_index: Option<u32>,
+    /// The participation the output carried, if any.
+    pub participation: Option<Participation>,
+}
+
+/// The voting power and/or staking reward an output accumulated for one event.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct ParticipationTally {
+    /// The accumulated voting power, i.e. `amount` times the number of qualifying milestones.
+    pub voting_power: u64,
+    /// The accumulated staking reward, i.e. the event's per-milestone rate applied to `amount` and summed.
+    pub staking_reward: u64,
+}
+
+/// Tallies `record`'s [`ParticipationTally`] for `event`, up to `current_milestone_index`.
+///
+/// Walks every milestone in `[event.milestone_index_start, min(current_milestone_index,
+/// event.milestone_index_end, spent_milestone_index))`, skipping milestones before `event.milestone_index_commence`
+/// and any milestone at which `record`'s attached participation fails [`Participations::validate`] for `event`
+/// (e.g. because the event hadn't started accepting participations there yet, or the answers are invalid) — those
+/// contribute nothing to the tally rather than ending it early, since a later milestone may validate again.
+pub fn voting_power(record: &OutputParticipationRecord, event: &EventInfo, current_milestone_index: u32) -> ParticipationTally {
+    let mut tally = ParticipationTally::default();
+
+    let Some(participation) = &record.participation else {
+        return tally;
+    };
+
+    let end_milestone_index = event
+        .milestone_index_end
+        .min(record.spent_milestone_index.unwrap_or(current_milestone_index))
+        .min(current_milestone_index);
+    let start_milestone_index = event
+        .milestone_index_start
+        .max(record.created_milestone_index)
+        .max(event.milestone_index_commence);
+
+    let participations = Participations {
+        participations: vec![participation.clone()],
+    };
+
+    for milestone_index in start_milestone_index..end_milestone_index {
+        let mut event_at_milestone = event.clone();
+        event_at_milestone.current_milestone_index = Some(milestone_index);
+        if participations.validate(std::slice::from_ref(&event_at_milestone)).is_err() {
+            continue;
+        }
+
+        match &event.payload {
+            EventPayload::Voting { .. } => tally.voting_power += record.amount,
+            EventPayload::Staking { numerator, denominator, .. } => {
+                tally.staking_reward += record.amount.saturating_mul(*numerator) / (*denominator).max(1);
+            }
+        }
+    }
+
+    tally
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{voting_power, OutputParticipationRecord};
+    use crate::node_api::participation::types::{EventId, EventInfo, EventPayload, Participation};
+
+    fn voting_event() -> EventInfo {
+        EventInfo {
+            event_id: EventId::from_str("0x09c2338f3acd51e626cc074d1abcb12d747076ddfccd5215d8f2f21af1aac111").unwrap(),
+            name: "Test vote".to_string(),
+            milestone_index_commence: 10,
+            milestone_index_start: 10,
+            milestone_index_end: 20,
+            payload: EventPayload::Voting { questions: vec![] },
+            additional_info: String::new(),
+            current_milestone_index: None,
+        }
+    }
+
+    #[test]
+    fn accrues_voting_power_only_within_the_event_window() {
+        let event = voting_event();
+        let record = OutputParticipationRecord {
+            amount: 100,
+            created_milestone_index: 5,
+            spent_milestone_index: None,
+            participation: Some(Participation {
+                event_id: event.event_id,
+                answers: vec![],
+            }),
+        };
+
+        // Window is [10, 20), so only 10 of the 30 milestones up to "current" qualify.
+        let tally = voting_power(&record, &event, 40);
+
+        assert_eq!(tally.voting_power, 100 * 10);
+        assert_eq!(tally.staking_reward, 0);
+    }
+
+    #[test]
+    fn unparticipating_output_tallies_to_zero() {
+        let event = voting_event();
+        let record = OutputParticipationRecord {
+            amount: 100,
+            created_milestone_index: 5,
+            spent_milestone_index: None,
+            participation: None,
+        };
+
+        assert_eq!(voting_power(&record, &event, 40), Default::default());
+    }
+}
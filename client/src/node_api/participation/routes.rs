@@ -0,0 +1,130 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Routes of the node's `participation` plugin, as free functions taking a `&Client`: discovering voting/staking
+//! events and casting participations.
+
+use std::collections::HashMap;
+
+use crate::{
+    block::output::OutputId,
+    block::payload::{tagged_data::TaggedDataPayload, Payload},
+    node_api::participation::{
+        rewards::{voting_power as tally_voting_power, OutputParticipationRecord, ParticipationTally},
+        types::{EventId, EventInfo, EventStatus, Participations},
+    },
+    Client, Result,
+};
+
+const PARTICIPATION_TAG: &[u8] = b"PARTICIPATE";
+
+/// GET /api/plugins/participation/v1/events endpoint, returning the ids of every event the node knows about.
+pub async fn events(client: &Client) -> Result<Vec<EventId>> {
+    #[derive(serde::Deserialize)]
+    struct EventsResponse {
+        #[serde(rename = "eventIds")]
+        event_ids: Vec<EventId>,
+    }
+
+    let response: EventsResponse = client
+        .node_manager
+        .get_request("api/plugins/participation/v1/events", None, client.get_timeout(), false, true)
+        .await?;
+
+    Ok(response.event_ids)
+}
+
+/// GET /api/plugins/participation/v1/events/{eventId} endpoint.
+pub async fn event(client: &Client, event_id: &EventId) -> Result<EventInfo> {
+    let path = format!("api/plugins/participation/v1/events/{event_id}");
+
+    client
+        .node_manager
+        .get_request(&path, None, client.get_timeout(), false, true)
+        .await
+}
+
+/// GET /api/plugins/participation/v1/events/{eventId}/status endpoint.
+pub async fn event_status(client: &Client, event_id: &EventId) -> Result<EventStatus> {
+    let path = format!("api/plugins/participation/v1/events/{event_id}/status");
+
+    client
+        .node_manager
+        .get_request(&path, None, client.get_timeout(), false, true)
+        .await
+}
+
+/// Fetches `output_id`'s participation record and the event's current status, then tallies the output's
+/// [`ParticipationTally`] for `event` via [`rewards::voting_power`](crate::node_api::participation::rewards::
+/// voting_power).
+pub async fn voting_power(client: &Client, output_id: &OutputId, event: &EventInfo) -> Result<ParticipationTally> {
+    let path = format!("api/plugins/participation/v1/outputs/{output_id}");
+    let record: OutputParticipationRecord = client
+        .node_manager
+        .get_request(&path, None, client.get_timeout(), false, true)
+        .await?;
+
+    let status = event_status(client, &event.event_id).await?;
+
+    Ok(tally_voting_power(&record, event, status.milestone_index))
+}
+
+/// GET /api/plugins/participation/v1/addresses/{bech32Address}/outputs, then tallies every participation-bearing
+/// output's voting power/staking reward, grouped by event id. Fetches each referenced event's info at most once.
+pub async fn participation_rewards(client: &Client, address: &str) -> Result<Vec<(EventId, ParticipationTally)>> {
+    #[derive(serde::Deserialize)]
+    struct AddressOutputsResponse {
+        #[serde(rename = "outputIds")]
+        output_ids: Vec<OutputId>,
+    }
+
+    let path = format!("api/plugins/participation/v1/addresses/{address}/outputs");
+    let response: AddressOutputsResponse = client
+        .node_manager
+        .get_request(&path, None, client.get_timeout(), false, true)
+        .await?;
+
+    let mut tallies: HashMap<EventId, ParticipationTally> = HashMap::new();
+    let mut event_cache: HashMap<EventId, EventInfo> = HashMap::new();
+
+    for output_id in response.output_ids {
+        let record_path = format!("api/plugins/participation/v1/outputs/{output_id}");
+        let record: OutputParticipationRecord = client
+            .node_manager
+            .get_request(&record_path, None, client.get_timeout(), false, true)
+            .await?;
+
+        let Some(participation) = &record.participation else {
+            continue;
+        };
+        let event_id = participation.event_id;
+
+        let event_info = match event_cache.get(&event_id) {
+            Some(event_info) => event_info.clone(),
+            None => {
+                let event_info = event(client, &event_id).await?;
+                event_cache.insert(event_id, event_info.clone());
+                event_info
+            }
+        };
+
+        let status = event_status(client, &event_id).await?;
+        let tally = tally_voting_power(&record, &event_info, status.milestone_index);
+
+        let entry = tallies.entry(event_id).or_default();
+        entry.voting_power += tally.voting_power;
+        entry.staking_reward += tally.staking_reward;
+    }
+
+    Ok(tallies.into_iter().collect())
+}
+
+/// Encodes `participations` the way the participation plugin expects and wraps it in a [`TaggedDataPayload`] tagged
+/// `PARTICIPATE`, so it can be attached to a transaction's outputs to cast a vote or start staking in one call, e.g.
+/// via `client.block().with_payload(participation_payload(&participations)?).finish().await?`.
+pub fn participation_payload(participations: &Participations) -> Result<Payload> {
+    Ok(Payload::TaggedData(Box::new(TaggedDataPayload::new(
+        PARTICIPATION_TAG.to_vec(),
+        participations.to_bytes()?,
+    )?)))
+}
@@ -0,0 +1,151 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::node_api::participation::types::EventId;
+
+/// The kind of a participation event.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventType {
+    /// A voting event, answered with one answer value per question.
+    Voting,
+    /// A staking event, joined by simply participating (no questions).
+    Staking,
+}
+
+/// One selectable answer to a [`Question`].
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Answer {
+    /// The value that [`Participation::answers`](super::Participation::answers) must contain to select this answer.
+    pub value: u8,
+    /// The human-readable label of the answer.
+    pub text: String,
+    /// Additional information about the answer.
+    #[serde(default)]
+    pub additional_info: String,
+}
+
+/// One question of a voting event, with its valid answer set.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Question {
+    /// The human-readable question text.
+    pub text: String,
+    /// The valid answers for this question.
+    pub answers: Vec<Answer>,
+    /// Additional information about the question.
+    #[serde(default)]
+    pub additional_info: String,
+}
+
+/// Parameters specific to a voting or staking event.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum EventPayload {
+    /// A voting event's questions.
+    Voting {
+        /// The questions being voted on.
+        questions: Vec<Question>,
+    },
+    /// A staking event's reward parameters.
+    Staking {
+        /// The symbol of the rewarded token.
+        text: String,
+        /// The amount of tokens rewarded per milestone per staked token.
+        numerator: u64,
+        /// The divisor applied to `numerator`.
+        denominator: u64,
+    },
+}
+
+/// Typed information about a participation event, as returned by the node's participation plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventInfo {
+    /// The id of the event.
+    pub event_id: EventId,
+    /// The human-readable name of the event.
+    pub name: String,
+    /// The milestone indices bounding the event.
+    pub milestone_index_commence: u32,
+    /// The milestone index at which the event starts accepting participations.
+    pub milestone_index_start: u32,
+    /// The milestone index at which the event no longer accepts participations.
+    pub milestone_index_end: u32,
+    /// The event-specific payload (questions for voting, reward parameters for staking).
+    pub payload: EventPayload,
+    /// Additional information about the event.
+    #[serde(default)]
+    pub additional_info: String,
+    /// The milestone index the node considers current, if known. Used by [`Participations::validate`](super::
+    /// Participations::validate) to check the event's voting window; left unset when the event info wasn't fetched
+    /// alongside up-to-date node state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_milestone_index: Option<u32>,
+}
+
+impl EventInfo {
+    /// The [`EventType`] of this event.
+    pub fn event_type(&self) -> EventType {
+        match self.payload {
+            EventPayload::Voting { .. } => EventType::Voting,
+            EventPayload::Staking { .. } => EventType::Staking,
+        }
+    }
+
+    /// Whether `milestone_index` falls inside this event's voting/staking window, i.e. on or after
+    /// [`Self::milestone_index_start`] and before [`Self::milestone_index_end`].
+    pub fn is_accepting_participation_at(&self, milestone_index: u32) -> bool {
+        milestone_index >= self.milestone_index_start && milestone_index < self.milestone_index_end
+    }
+}
+
+/// The current phase of a participation event.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EventStatusPhase {
+    /// The event has been created but hasn't started accepting participations yet.
+    Upcoming,
+    /// The event is commenced and currently accepting participations.
+    Commencing,
+    /// The event has ended.
+    Ended,
+}
+
+/// The status of one answer of a voting event question, as tallied by the node.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AnswerStatus {
+    /// The answer value, matching [`Answer::value`].
+    pub value: u8,
+    /// The cumulative voting power of all participations selecting this answer so far.
+    pub current: u64,
+    /// The cumulative voting power of all participations that selected this answer over the event's whole duration.
+    pub accumulated: u64,
+}
+
+/// The status of one question of a voting event, as tallied by the node.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionStatus {
+    /// The tallied status of each of the question's answers.
+    pub answers: Vec<AnswerStatus>,
+}
+
+/// The live status of a participation event, as returned by the node's participation plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventStatus {
+    /// The id of the event this status is about.
+    pub event_id: EventId,
+    /// The milestone index this status was computed at.
+    pub milestone_index: u32,
+    /// The current phase of the event.
+    pub status: EventStatusPhase,
+    /// The tallied status of each question, empty for staking events.
+    #[serde(default)]
+    pub questions: Vec<QuestionStatus>,
+}
@@ -0,0 +1,66 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fmt, str::FromStr};
+
+use packable::Packable;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The identifier of a participation event, hex encoded `[u8; 32]`.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Packable)]
+pub struct EventId([u8; Self::LENGTH]);
+
+impl EventId {
+    /// The length, in bytes, of an [`EventId`].
+    pub const LENGTH: usize = 32;
+
+    /// Creates a new [`EventId`] from its raw bytes.
+    pub fn new(bytes: [u8; Self::LENGTH]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for EventId {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromStr for EventId {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex_str = s.strip_prefix("0x").unwrap_or(s);
+        let bytes: [u8; Self::LENGTH] = hex::decode(hex_str)
+            .map_err(|_| crate::Error::InvalidParticipationEventId(s.to_string()))?
+            .try_into()
+            .map_err(|_| crate::Error::InvalidParticipationEventId(s.to_string()))?;
+
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::Display for EventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+impl fmt::Debug for EventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EventId({})", self)
+    }
+}
+
+impl Serialize for EventId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EventId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,14 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types for interacting with a node's participation plugin (voting and staking events).
+
+mod event;
+mod event_id;
+mod participation;
+
+pub use event::{
+    Answer, AnswerStatus, EventInfo, EventPayload, EventStatus, EventStatusPhase, EventType, Question, QuestionStatus,
+};
+pub use event_id::EventId;
+pub use participation::{Participation, Participations};
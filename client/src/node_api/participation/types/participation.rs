@@ -1,12 +1,12 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{convert::TryInto, io::Read};
+use std::{collections::HashSet, convert::TryInto, io::Read};
 
 use packable::PackableExt;
 use serde::{Deserialize, Serialize};
 
-use crate::node_api::participation::types::EventId;
+use crate::node_api::participation::types::{EventId, EventInfo, EventPayload};
 
 /// Participation information.
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -81,6 +81,66 @@ impl Participations {
 
         Ok(Participations { participations })
     }
+
+    /// Checks `self` against already-fetched `events` before it's encoded, catching answers the node would
+    /// otherwise reject after broadcast: duplicate event ids within `self`, answers submitted outside an event's
+    /// voting window, the wrong number of answers for an event's questions, and answer values an event doesn't
+    /// declare. A referenced event that isn't present in `events` is left unchecked.
+    pub fn validate(&self, events: &[EventInfo]) -> crate::Result<()> {
+        let mut seen_event_ids = HashSet::new();
+        for participation in &self.participations {
+            if !seen_event_ids.insert(participation.event_id) {
+                return Err(crate::Error::DuplicateParticipationEventId(participation.event_id));
+            }
+        }
+
+        for participation in &self.participations {
+            let event = match events.iter().find(|event| event.event_id == participation.event_id) {
+                Some(event) => event,
+                None => continue,
+            };
+
+            if let Some(current_milestone_index) = event.current_milestone_index {
+                if !event.is_accepting_participation_at(current_milestone_index) {
+                    return Err(crate::Error::ParticipationEventNotAcceptingParticipations(
+                        participation.event_id,
+                        current_milestone_index,
+                    ));
+                }
+            }
+
+            let expected_answers = match &event.payload {
+                EventPayload::Voting { questions } => questions.len(),
+                EventPayload::Staking { .. } => 0,
+            };
+            if participation.answers.len() != expected_answers {
+                return Err(crate::Error::ParticipationAnswerCountMismatch {
+                    event_id: participation.event_id,
+                    expected: expected_answers,
+                    actual: participation.answers.len(),
+                });
+            }
+
+            if let EventPayload::Voting { questions } = &event.payload {
+                for (question, &answer) in questions.iter().zip(participation.answers.iter()) {
+                    if !question.answers.iter().any(|valid_answer| valid_answer.value == answer) {
+                        return Err(crate::Error::InvalidParticipationAnswerValue {
+                            event_id: participation.event_id,
+                            answer,
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs [`Self::validate`] against `events` and, if it passes, encodes `self` with [`Self::to_bytes`].
+    pub fn to_bytes_checked(&self, events: &[EventInfo]) -> crate::Result<Vec<u8>> {
+        self.validate(events)?;
+        self.to_bytes()
+    }
 }
 
 #[cfg(test)]
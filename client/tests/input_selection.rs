@@ -0,0 +1,5 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[path = "input_selection/mod.rs"]
+mod input_selection;
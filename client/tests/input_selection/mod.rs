@@ -0,0 +1,48 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the mana decay/generation math behind [`InputSelection::select`](iota_client::api::
+//! input_selection::new::InputSelection::select).
+//!
+//! A previous version of this file declared a `Fixture` builder meant to drive `InputSelection::select` end to
+//! end (covering amount/mana/burn/branch-and-bound selection), but it depended on an `InputSigningData`
+//! constructor, and `Address`/`AliasId`/`NftId`/`OutputId` helpers, that aren't exposed anywhere in the crate
+//! (confirmed by grep) and can't be added from here: `InputSigningData` is defined in `iota_client` itself, and
+//! this file, being an external integration test, can't add inherent methods to a type it doesn't own. Until the
+//! crate exposes a way to construct `InputSigningData` for tests, only the pure, already-`pub` mana math is
+//! testable from outside the crate; the rest of the selection logic is untested.
+
+#![cfg(feature = "test-utils")]
+
+use iota_client::{
+    api::input_selection::new::{decay_mana, potential_mana},
+    block::protocol::protocol_parameters,
+};
+
+#[test]
+fn decay_mana_zero_epochs_elapsed_is_a_noop() {
+    let protocol_parameters = protocol_parameters();
+
+    assert_eq!(decay_mana(1_000_000, &protocol_parameters, 0), 1_000_000);
+}
+
+#[test]
+fn decay_mana_zero_mana_stays_zero() {
+    let protocol_parameters = protocol_parameters();
+
+    assert_eq!(decay_mana(0, &protocol_parameters, 10), 0);
+}
+
+#[test]
+fn potential_mana_zero_elapsed_slots_generates_nothing() {
+    let protocol_parameters = protocol_parameters();
+
+    assert_eq!(potential_mana(1_000_000, &protocol_parameters, 0), 0);
+}
+
+#[test]
+fn potential_mana_zero_amount_generates_nothing() {
+    let protocol_parameters = protocol_parameters();
+
+    assert_eq!(potential_mana(0, &protocol_parameters, 1_000), 0);
+}
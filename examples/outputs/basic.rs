@@ -101,6 +101,6 @@ async fn main() -> Result<()> {
         "Message metadata: http://localhost:14265/api/v2/messages/{}/metadata",
         message.id()
     );
-    let _ = client.retry_until_included(&message.id(), None, None).await?;
+    let _ = client.retry_until_included(&message.id(), None).await?;
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,185 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Multi-threaded PoW miner.
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc,
+};
+
+use crypto::{
+    encoding::ternary::{b1t6, Btrit, T1B1Buf, TritBuf},
+    hashes::{
+        blake2b::Blake2b256,
+        ternary::{
+            curl_p::{CurlPBatchHasher, BATCH_SIZE},
+            HASH_LENGTH,
+        },
+        Digest,
+    },
+};
+
+use super::{Error, LN_3};
+
+// Should take around one second to reach on an average CPU, so shouldn't cause a noticeable delay on
+// timeout_in_seconds.
+const POW_ROUNDS_BEFORE_INTERVAL_CHECK: usize = 3000;
+
+/// Multi-threaded proof-of-work, partitioning the nonce space across a fixed number of worker threads.
+pub struct MultiThreadedMiner {
+    num_workers: usize,
+    timeout_in_seconds: Option<u64>,
+}
+
+/// Builder for [`MultiThreadedMiner`].
+#[derive(Default)]
+#[must_use]
+pub struct MultiThreadedMinerBuilder {
+    num_workers: Option<usize>,
+    timeout_in_seconds: Option<u64>,
+}
+
+impl MultiThreadedMinerBuilder {
+    /// Create a new `MultiThreadedMinerBuilder`.
+    pub fn new() -> Self {
+        Self { ..Default::default() }
+    }
+
+    /// Sets the number of worker threads. Defaults to the number of available cores.
+    pub fn with_num_workers(mut self, num_workers: usize) -> Self {
+        self.num_workers = Some(num_workers);
+        self
+    }
+
+    /// Aborts and returns a "cancelled" error after the interval elapses, if set.
+    /// New parents (tips) should be fetched and proof-of-work re-run afterwards.
+    pub fn with_timeout_in_seconds(mut self, timeout_in_seconds: u64) -> Self {
+        self.timeout_in_seconds = Some(timeout_in_seconds);
+        self
+    }
+
+    /// Build the MultiThreadedMiner.
+    pub fn finish(self) -> MultiThreadedMiner {
+        MultiThreadedMiner {
+            num_workers: self
+                .num_workers
+                .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get())),
+            timeout_in_seconds: self.timeout_in_seconds,
+        }
+    }
+}
+
+impl MultiThreadedMiner {
+    /// Mine a nonce for provided bytes, splitting the nonce space across `num_workers` threads that each step by
+    /// `num_workers * BATCH_SIZE`, so no two workers ever hash the same nonce.
+    pub fn nonce(&self, bytes: &[u8], target_score: u32) -> Result<u64, Error> {
+        let mut pow_digest = TritBuf::<T1B1Buf>::new();
+        let target_zeros =
+            (((bytes.len() + std::mem::size_of::<u64>()) as f64 * target_score as f64).ln() / LN_3).ceil() as usize;
+        if target_zeros > HASH_LENGTH {
+            return Err(Error::InvalidPowScore(target_score, target_zeros));
+        }
+
+        let hash = Blake2b256::digest(bytes);
+        b1t6::encode::<T1B1Buf>(&hash).iter().for_each(|t| pow_digest.push(t));
+
+        // `found`/`winning_nonce` are the only state shared between workers, and both are only ever written once
+        // (by whichever worker wins the race), so plain atomics are enough; there's no contended critical section
+        // that would benefit from a heavier lock.
+        let found = Arc::new(AtomicBool::new(false));
+        let winning_nonce = Arc::new(AtomicU64::new(0));
+        let num_workers = self.num_workers.max(1);
+
+        let workers: Vec<_> = (0..num_workers)
+            .map(|worker_index| {
+                let pow_digest = pow_digest.clone();
+                let found = Arc::clone(&found);
+                let winning_nonce = Arc::clone(&winning_nonce);
+                let timeout_in_seconds = self.timeout_in_seconds;
+
+                std::thread::spawn(move || {
+                    mine_worker(
+                        &pow_digest,
+                        target_zeros,
+                        worker_index as u64,
+                        num_workers as u64,
+                        timeout_in_seconds,
+                        &found,
+                        &winning_nonce,
+                    )
+                })
+            })
+            .collect();
+
+        let mut cancelled = false;
+        for worker in workers {
+            if let Ok(worker_cancelled) = worker.join() {
+                cancelled |= worker_cancelled && !found.load(Ordering::SeqCst);
+            }
+        }
+
+        if found.load(Ordering::SeqCst) {
+            Ok(winning_nonce.load(Ordering::SeqCst))
+        } else {
+            debug_assert!(cancelled, "all workers exhausted their nonce space without finding one");
+            Err(Error::Cancelled)
+        }
+    }
+}
+
+/// Runs one worker's share of the search, starting at `worker_index * BATCH_SIZE` and stepping by
+/// `num_workers * BATCH_SIZE`. Returns whether the worker stopped because of a timeout rather than because someone
+/// (possibly itself) found a winning nonce.
+#[allow(clippy::too_many_arguments)]
+fn mine_worker(
+    pow_digest: &TritBuf<T1B1Buf>,
+    target_zeros: usize,
+    worker_index: u64,
+    num_workers: u64,
+    timeout_in_seconds: Option<u64>,
+    found: &AtomicBool,
+    winning_nonce: &AtomicU64,
+) -> bool {
+    let mut hasher = CurlPBatchHasher::<T1B1Buf>::new(HASH_LENGTH);
+    let mut buffers = Vec::<TritBuf<T1B1Buf>>::with_capacity(BATCH_SIZE);
+    for _ in 0..BATCH_SIZE {
+        let mut buffer = TritBuf::<T1B1Buf>::zeros(HASH_LENGTH);
+        buffer[..pow_digest.len()].copy_from(pow_digest);
+        buffers.push(buffer);
+    }
+
+    let step = num_workers * BATCH_SIZE as u64;
+    let mut nonce = worker_index * BATCH_SIZE as u64;
+
+    // Counter to reduce number of mining_start.elapsed() calls.
+    let mut counter = 0;
+    let mining_start = instant::Instant::now();
+    loop {
+        if counter % POW_ROUNDS_BEFORE_INTERVAL_CHECK == 0 {
+            if found.load(Ordering::SeqCst) {
+                return false;
+            }
+            if let Some(tips_interval) = timeout_in_seconds {
+                if mining_start.elapsed() > instant::Duration::from_secs(tips_interval) {
+                    return true;
+                }
+            }
+        }
+
+        for (i, buffer) in buffers.iter_mut().enumerate() {
+            let nonce_trits = b1t6::encode::<T1B1Buf>(&(nonce + i as u64).to_le_bytes());
+            buffer[pow_digest.len()..pow_digest.len() + nonce_trits.len()].copy_from(&nonce_trits);
+            hasher.add(buffer.clone());
+        }
+        for (i, hash) in hasher.hash().enumerate() {
+            let trailing_zeros = hash.iter().rev().take_while(|t| *t == Btrit::Zero).count();
+            if trailing_zeros >= target_zeros && !found.swap(true, Ordering::SeqCst) {
+                winning_nonce.store(nonce + i as u64, Ordering::SeqCst);
+                return false;
+            }
+        }
+        nonce += step;
+        counter += 1;
+    }
+}
@@ -149,6 +149,75 @@ pub(crate) fn sort_input_signing_data(inputs: Vec<InputSigningData>) -> crate::R
     Ok(sorted_inputs)
 }
 
+/// The unix timestamp at which `output` becomes unconditionally spendable, given its timelock/expiration unlock
+/// conditions, or `None` if it already is. Outputs with neither condition are always spendable.
+pub(crate) fn blocked_until(output: &Output, current_time: u32) -> Option<u32> {
+    let unlock_conditions = output.unlock_conditions()?;
+
+    // Both conditions have to have elapsed, so the later of the two pending timestamps is the one that counts.
+    let timelock = unlock_conditions
+        .timelock()
+        .map(|timelock| timelock.timestamp())
+        .filter(|timestamp| current_time < *timestamp);
+    let expiration = unlock_conditions
+        .expiration()
+        .map(|expiration| expiration.timestamp())
+        .filter(|timestamp| current_time < *timestamp);
+
+    timelock.max(expiration)
+}
+
+/// Checks that `inputs` cover `required_amount` using only outputs that are currently spendable, i.e. not blocked
+/// by a timelock or expiration condition that hasn't elapsed yet.
+///
+/// Returns [`crate::Error::InsufficientSpendableFunds`] when the spendable inputs fall short but all inputs together
+/// cover `required_amount`. Its `seconds_until_enough` is the wait until the soonest-unlocking blocked output becomes
+/// spendable; that output alone does not necessarily cover the shortfall. This lets a caller schedule a retry
+/// instead of failing with a flat "insufficient funds", the way a plain balance sum against `required_amount` would.
+///
+/// Returns [`crate::Error::NotEnoughBalance`] when `total_amount` (i.e. every input, blocked or not) still falls
+/// short of `required_amount`: no amount of waiting fixes that, so it's a genuine shortfall rather than a
+/// predictable delay.
+pub(crate) fn check_spendable_or_predict_wait(
+    inputs: &[InputSigningData],
+    required_amount: u64,
+    current_time: u32,
+) -> crate::Result<()> {
+    let mut spendable_amount = 0u64;
+    let mut total_amount = 0u64;
+    let mut seconds_until_enough: Option<u32> = None;
+
+    for input in inputs {
+        let amount = input.output.amount();
+        total_amount += amount;
+
+        match blocked_until(&input.output, current_time) {
+            None => spendable_amount += amount,
+            Some(unlock_timestamp) => {
+                let wait = unlock_timestamp.saturating_sub(current_time);
+                seconds_until_enough = Some(seconds_until_enough.map_or(wait, |shortest| shortest.min(wait)));
+            }
+        }
+    }
+
+    if spendable_amount >= required_amount {
+        return Ok(());
+    }
+
+    if total_amount < required_amount {
+        return Err(crate::Error::NotEnoughBalance(total_amount, required_amount));
+    }
+
+    match seconds_until_enough {
+        Some(seconds_until_enough) => Err(crate::Error::InsufficientSpendableFunds {
+            available: spendable_amount,
+            required: required_amount,
+            seconds_until_enough,
+        }),
+        None => Ok(()),
+    }
+}
+
 // Check if an address is required for unlockig an output in any unlock condition
 pub(crate) fn output_contains_address(output: &Output, address: &Address, current_time: u32) -> bool {
     if let Some(unlock_conditions) = output.unlock_conditions() {
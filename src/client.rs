@@ -30,14 +30,16 @@ use bee_rest_api::types::{
         UtxoChangesResponse as MilestoneUTXOChanges,
     },
 };
+use arc_swap::ArcSwap;
 use crypto::keys::slip10::Seed;
+use futures::stream::{StreamExt, TryStreamExt};
 #[cfg(feature = "wasm")]
 use gloo_timers::future::TimeoutFuture;
 use packable::PackableExt;
+use rand::Rng;
 use url::Url;
 #[cfg(not(feature = "wasm"))]
 use {
-    crate::api::finish_pow,
     std::collections::HashMap,
     tokio::{
         runtime::Runtime,
@@ -47,9 +49,17 @@ use {
 };
 #[cfg(feature = "mqtt")]
 use {
-    crate::node_api::mqtt::{BrokerOptions, MqttEvent, MqttManager, TopicHandlerMap},
+    crate::node_api::mqtt::{BrokerOptions, MqttEvent, MqttManager, Topic, TopicHandlerMap},
     rumqttc::AsyncClient as MqttClient,
-    tokio::sync::watch::{Receiver as WatchReceiver, Sender as WatchSender},
+    tokio::sync::{
+        mpsc::UnboundedReceiver,
+        watch::{Receiver as WatchReceiver, Sender as WatchSender},
+    },
+};
+#[cfg(feature = "mdns")]
+use {
+    mdns_sd::{ServiceDaemon, ServiceEvent},
+    std::time::Instant,
 };
 
 use crate::{
@@ -69,6 +79,59 @@ use crate::{
     },
 };
 
+/// Default interval between primary-node health checks performed by the connectivity watchdog.
+#[cfg(not(feature = "wasm"))]
+const DEFAULT_CONNECTIVITY_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// Default number of consecutive failed health checks before the watchdog fails over to another node.
+#[cfg(not(feature = "wasm"))]
+const DEFAULT_MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+/// Number of addresses [`Client::verify_transaction_essence`] checks, starting from index 0, when deciding whether a
+/// remainder output is controlled by the provided signer.
+const DEFAULT_CHANGE_ADDRESS_SEARCH_RANGE: std::ops::Range<usize> = 0..30;
+
+/// The mDNS service type HORNET/Bee nodes advertise themselves under for [`Client::start_mdns_discovery`].
+#[cfg(feature = "mdns")]
+const MDNS_SERVICE_TYPE: &str = "_iota-node._tcp.local.";
+
+/// Capacity of the [`ClientEvent`] broadcast channel. Lagging subscribers miss the oldest events rather than
+/// blocking [`Client::sync_nodes`].
+#[allow(dead_code)] // consumed by `ClientBuilder::finish` when constructing `Client::event_sender`
+#[cfg(not(feature = "wasm"))]
+const DEFAULT_EVENT_CHANNEL_CAPACITY: usize = 64;
+
+/// Default number of nodes a quorum read is fanned out to, used by `ClientBuilder::with_quorum_size` when unset.
+#[allow(dead_code)] // consumed by the (external) `ClientBuilder::finish`
+const DEFAULT_QUORUM_SIZE: usize = 3;
+/// Default number of agreeing responses a quorum read requires, used by `ClientBuilder::with_min_quorum_size`
+/// when unset.
+#[allow(dead_code)] // consumed by the (external) `ClientBuilder::finish`
+const DEFAULT_MIN_QUORUM_SIZE: usize = 2;
+
+/// Default number of node requests [`Client::find_messages`]/[`Client::find_outputs`] keep in flight at once,
+/// used by `ClientBuilder::with_max_parallel_requests` when unset.
+#[allow(dead_code)] // consumed by the (external) `ClientBuilder::finish`
+const DEFAULT_MAX_PARALLEL_REQUESTS: usize = 25;
+
+/// A connectivity/network event emitted by [`Client::sync_nodes`], observable via [`Client::event_receiver`].
+#[cfg(not(feature = "wasm"))]
+#[derive(Debug, Clone)]
+pub enum ClientEvent {
+    /// The set of synced nodes changed.
+    SyncedNodesChanged,
+    /// The detected network id or bech32_hrp changed.
+    NetworkChanged {
+        /// The newly detected network id.
+        network_id: Option<u64>,
+        /// The newly detected bech32_hrp.
+        bech32_hrp: String,
+    },
+    /// A previously synced node stopped responding.
+    NodeUnhealthy(Node),
+    /// A previously unhealthy node is reachable again.
+    NodeBack(Node),
+}
+
 /// NodeInfo wrapper which contains the nodeinfo and the url from the node (useful when multiple nodes are used)
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NodeInfoWrapper {
@@ -90,6 +153,10 @@ pub struct Client {
     /// Flag to stop the node syncing
     #[cfg(not(feature = "wasm"))]
     pub(crate) sync_kill_sender: Option<Arc<Sender<()>>>,
+    /// Broadcasts connectivity/network events emitted by [`Client::sync_nodes`]; subscribe via
+    /// [`Client::event_receiver`].
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) event_sender: Arc<Sender<ClientEvent>>,
     /// A MQTT client to subscribe/unsubscribe to topics.
     #[cfg(feature = "mqtt")]
     pub(crate) mqtt_client: Option<MqttClient>,
@@ -99,7 +166,40 @@ pub struct Client {
     pub(crate) broker_options: BrokerOptions,
     #[cfg(feature = "mqtt")]
     pub(crate) mqtt_event_channel: (Arc<WatchSender<MqttEvent>>, WatchReceiver<MqttEvent>),
-    pub(crate) network_info: Arc<RwLock<NetworkInfo>>,
+    /// An atomically-swappable immutable snapshot: readers clone a cheap [`Arc`] with no locking (and nothing to
+    /// poison), while the sync/[`Client::get_network_info`] paths build a fresh [`NetworkInfo`] and `store` it in
+    /// one shot. Keeps hot-path message construction off the lock the syncing task writes under.
+    pub(crate) network_info: Arc<ArcSwap<NetworkInfo>>,
+    /// The node most recently confirmed reachable by the connectivity watchdog, preferred by [`Client::get_node`]
+    /// over `node_manager.primary_node` once failover has promoted a different node.
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) verified_primary_node: Arc<RwLock<Option<Node>>>,
+    /// Flag to stop the connectivity watchdog.
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) connectivity_kill_sender: Option<Arc<Sender<()>>>,
+    /// Every configured node the connectivity watchdog most recently found reachable, refreshed on
+    /// `ClientBuilder::with_node_health_interval` (falling back to [`DEFAULT_CONNECTIVITY_CHECK_INTERVAL`]),
+    /// independent of `node_manager.synced_nodes`. See [`Client::healthy_nodes`].
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) healthy_nodes: Arc<RwLock<HashSet<Node>>>,
+    /// Nodes discovered on the local network via mDNS, kept separate from `node_manager.nodes` so
+    /// [`Client::mdns_discovered_nodes`] can report them without conflating user-configured and auto-discovered
+    /// nodes.
+    #[cfg(feature = "mdns")]
+    pub(crate) mdns_nodes: Arc<RwLock<HashSet<Node>>>,
+    /// Flag to stop local mDNS discovery.
+    #[cfg(feature = "mdns")]
+    pub(crate) mdns_kill_sender: Option<Arc<Sender<()>>>,
+    /// Whether safety-critical reads (`get_output`, `get_included_message`, `get_milestone`, and the indexer
+    /// `*_output_ids` routes) are fanned out to multiple nodes and cross-checked for agreement, rather than
+    /// trusting a single node's response.
+    pub(crate) quorum: bool,
+    /// Number of synced nodes a quorum read is fanned out to.
+    pub(crate) quorum_size: usize,
+    /// Minimum number of agreeing responses required for a quorum read to succeed.
+    pub(crate) min_quorum_size: usize,
+    /// Maximum number of node requests [`Client::find_messages`]/[`Client::find_outputs`] keep in flight at once.
+    pub(crate) max_parallel_requests: usize,
     /// HTTP request timeout.
     pub(crate) api_timeout: Duration,
     /// HTTP request timeout for remote PoW API call.
@@ -127,6 +227,16 @@ impl Drop for Client {
             sender.send(()).expect("failed to stop syncing process");
         }
 
+        #[cfg(not(feature = "wasm"))]
+        if let Some(sender) = self.connectivity_kill_sender.take() {
+            sender.send(()).expect("failed to stop connectivity watchdog");
+        }
+
+        #[cfg(feature = "mdns")]
+        if let Some(sender) = self.mdns_kill_sender.take() {
+            sender.send(()).expect("failed to stop mDNS discovery");
+        }
+
         #[cfg(not(feature = "wasm"))]
         if let Some(runtime) = self.runtime.take() {
             if let Ok(runtime) = Arc::try_unwrap(runtime) {
@@ -147,6 +257,219 @@ impl Drop for Client {
     }
 }
 
+/// Controls the polling schedule [`Client::retry_until_included`] and [`Client::retry_until_included_stream`] use
+/// while waiting for a message to be included.
+///
+/// Each wait is `initial_interval * multiplier.powi(attempt)`, capped at `max_interval`, and optionally jittered by
+/// up to `±jitter` of that value so many clients retrying the same message don't all poll in lockstep. Polling
+/// stops, with [`Error::TangleInclusionError`], once either `max_attempts` polls or `timeout` (if set) has elapsed,
+/// whichever comes first.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub(crate) initial_interval: Duration,
+    pub(crate) multiplier: f64,
+    pub(crate) max_interval: Duration,
+    pub(crate) jitter: Option<f64>,
+    pub(crate) max_attempts: u64,
+    pub(crate) timeout: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    /// The previous hardcoded behaviour of `retry_until_included`: a fixed 5 second interval, up to 40 attempts.
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(5),
+            multiplier: 1.0,
+            max_interval: Duration::from_secs(5),
+            jitter: None,
+            max_attempts: 40,
+            timeout: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates the default retry policy (see [`Default`]).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Polls at a constant `interval`, preserving the previous default behaviour but with a custom interval.
+    pub fn fixed_interval(interval: Duration) -> Self {
+        Self {
+            initial_interval: interval,
+            multiplier: 1.0,
+            max_interval: interval,
+            ..Self::default()
+        }
+    }
+
+    /// Polls with exponential backoff, starting at `initial_interval` and growing by `multiplier` each attempt, up
+    /// to `max_interval`.
+    pub fn exponential_backoff(initial_interval: Duration, multiplier: f64, max_interval: Duration) -> Self {
+        Self {
+            initial_interval,
+            multiplier,
+            max_interval,
+            ..Self::default()
+        }
+    }
+
+    /// Randomizes each computed interval by up to `±fraction` of its value. `fraction` is clamped to `0.0..=1.0`.
+    pub fn with_jitter(mut self, fraction: f64) -> Self {
+        self.jitter = Some(fraction.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets the maximum number of polling attempts before giving up.
+    pub fn with_max_attempts(mut self, max_attempts: u64) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Bounds the overall time spent retrying, independent of `max_attempts`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The (possibly jittered) interval to wait before polling attempt `attempt` (0-indexed).
+    fn interval_for_attempt(&self, attempt: u32) -> Duration {
+        let scaled = self.initial_interval.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        let capped = scaled.min(self.max_interval.as_secs_f64());
+        let jittered = match self.jitter {
+            Some(fraction) if fraction > 0.0 => {
+                let delta = capped * fraction;
+                capped + rand::thread_rng().gen_range(-delta..=delta)
+            }
+            _ => capped,
+        };
+        Duration::from_secs_f64(jittered.max(0.0))
+    }
+}
+
+/// Strategy [`Client::find_inputs`] uses to pick which outputs cover a requested amount.
+#[derive(Debug, Clone)]
+pub enum InputSelectionStrategy {
+    /// Accumulate outputs largest-value-first until the amount is covered. Simple and fast, but frequently
+    /// overshoots and forces a remainder (change) output.
+    Greedy,
+    /// Depth-first branch-and-bound search for a subset of outputs whose summed value lands in
+    /// `[amount, amount + cost_of_change]`, so no remainder output is needed, preferring exact matches.
+    /// `cost_of_change` is the minimum amount a remainder output would need to carry; pass `0` to search for an
+    /// exact match only. Falls back to [`InputSelectionStrategy::Greedy`] if no qualifying subset is found within
+    /// the explored-node budget.
+    BranchAndBound {
+        /// Upper slack above the requested amount a selected subset may land in while still avoiding a remainder
+        /// output.
+        cost_of_change: u64,
+    },
+}
+
+impl Default for InputSelectionStrategy {
+    fn default() -> Self {
+        Self::Greedy
+    }
+}
+
+/// Depth-first branch-and-bound search over `values` (sorted descending, as [`Client::find_inputs`] already sorts
+/// its candidates) for a subset of indices summing into `[lower, upper]`, preferring exact (`== lower`) matches.
+/// Bounds the search to `INPUT_COUNT_MAX` selected indices and [`Self::MAX_EXPLORED_NODES`] explored branches.
+struct BranchAndBoundSearch<'a> {
+    values: &'a [u64],
+    // suffix_sum[i] is the sum of values[i..], used to prune branches that can never reach `lower`.
+    suffix_sum: Vec<u64>,
+    lower: u64,
+    upper: u64,
+    explored: u32,
+    best: Option<Vec<usize>>,
+}
+
+impl<'a> BranchAndBoundSearch<'a> {
+    const MAX_EXPLORED_NODES: u32 = 10_000;
+
+    fn new(values: &'a [u64], lower: u64, upper: u64) -> Self {
+        let mut suffix_sum = vec![0u64; values.len() + 1];
+        for i in (0..values.len()).rev() {
+            suffix_sum[i] = suffix_sum[i + 1] + values[i];
+        }
+        Self {
+            values,
+            suffix_sum,
+            lower,
+            upper,
+            explored: 0,
+            best: None,
+        }
+    }
+
+    /// Runs the search and returns the selected indices into `values`, if a qualifying subset was found.
+    fn run(mut self) -> Option<Vec<usize>> {
+        let mut selected = Vec::new();
+        self.search(0, 0, &mut selected);
+        self.best
+    }
+
+    fn search(&mut self, index: usize, current_sum: u64, selected: &mut Vec<usize>) {
+        if self.explored >= Self::MAX_EXPLORED_NODES || selected.len() > INPUT_COUNT_MAX as usize {
+            return;
+        }
+        self.explored += 1;
+
+        if current_sum > self.upper {
+            return;
+        }
+        if current_sum >= self.lower {
+            let better_than_current_best = match &self.best {
+                None => true,
+                // An exact match beats any non-exact one; among equally exact subsets, fewer inputs win.
+                Some(best) => {
+                    let best_is_exact = best.iter().map(|&i| self.values[i]).sum::<u64>() == self.lower;
+                    let is_exact = current_sum == self.lower;
+                    (is_exact && !best_is_exact) || (is_exact == best_is_exact && selected.len() < best.len())
+                }
+            };
+            if better_than_current_best {
+                self.best = Some(selected.clone());
+            }
+            // An exact match can't be improved on; no need to keep exploring this branch.
+            if current_sum == self.lower {
+                return;
+            }
+        }
+        if index >= self.values.len() || current_sum + self.suffix_sum[index] < self.lower {
+            return;
+        }
+
+        selected.push(index);
+        self.search(index + 1, current_sum + self.values[index], selected);
+        selected.pop();
+
+        self.search(index + 1, current_sum, selected);
+    }
+}
+
+/// One step of progress reported by [`Client::retry_until_included_stream`].
+#[derive(Debug, Clone)]
+pub enum RetryProgress {
+    /// The latest attachment was reattached under a new [`MessageId`].
+    Reattached(MessageId),
+    /// The latest attachment was promoted.
+    Promoted(MessageId),
+    /// An attachment was found in a conflicting state; still waiting to see if another attachment lands.
+    Conflicting(MessageId),
+    /// The message (or one of its reattachments) was included. This is always the final event.
+    Included(Vec<(MessageId, Message)>),
+}
+
+/// The outcome of one [`Client::retry_until_included_step`] iteration.
+enum RetryStep {
+    /// Inclusion wasn't resolved yet; carries whatever [`RetryProgress`] events happened during this iteration.
+    Continue(Vec<RetryProgress>),
+    /// The message (or a reattachment of it) was included.
+    Done(Vec<(MessageId, Message)>),
+}
+
 impl Client {
     /// Create the builder to instntiate the IOTA Client.
     pub fn builder() -> ClientBuilder {
@@ -160,7 +483,8 @@ impl Client {
         sync: Arc<RwLock<HashSet<Node>>>,
         nodes: HashSet<Node>,
         node_sync_interval: Duration,
-        network_info: Arc<RwLock<NetworkInfo>>,
+        network_info: Arc<ArcSwap<NetworkInfo>>,
+        event_sender: Arc<Sender<ClientEvent>>,
         mut kill: Receiver<()>,
     ) {
         let node_sync_interval = TokioDuration::from_nanos(
@@ -177,7 +501,7 @@ impl Client {
                             // delay first since the first `sync_nodes` call is made by the builder
                             // to ensure the node list is filled before the client is used
                             sleep(node_sync_interval).await;
-                            Client::sync_nodes(&sync, &nodes, &network_info).await;
+                            Client::sync_nodes(&sync, &nodes, &network_info, &event_sender).await;
                     } => {}
                     _ = kill.recv() => {}
                 }
@@ -189,10 +513,14 @@ impl Client {
     pub(crate) async fn sync_nodes(
         sync: &Arc<RwLock<HashSet<Node>>>,
         nodes: &HashSet<Node>,
-        network_info: &Arc<RwLock<NetworkInfo>>,
+        network_info: &Arc<ArcSwap<NetworkInfo>>,
+        event_sender: &Sender<ClientEvent>,
     ) {
         let mut synced_nodes = HashSet::new();
         let mut network_nodes: HashMap<String, Vec<(NodeInfo, Node)>> = HashMap::new();
+        let target_network = network_info.load().network.clone();
+        let previous_synced = sync.read().map_or(HashSet::new(), |synced| synced.clone());
+        let previous_network_info = network_info.load_full();
         for node in nodes {
             // Put the healthy node url into the network_nodes
             if let Ok(info) = Client::get_node_info(&node.url.to_string(), None).await {
@@ -201,10 +529,7 @@ impl Client {
                         Some(network_id_entry) => {
                             network_id_entry.push((info, node.clone()));
                         }
-                        None => match &network_info
-                            .read()
-                            .map_or(NetworkInfo::default().network, |info| info.network.clone())
-                        {
+                        None => match &target_network {
                             Some(id) => {
                                 if info.protocol.network_name.contains(id) {
                                     network_nodes
@@ -228,32 +553,245 @@ impl Client {
             }
         }
         if let Some(nodes) = network_nodes.get(most_nodes.0) {
+            if let Some((first_info, _)) = nodes.first() {
+                let mut updated_network_info = (*network_info.load_full()).clone();
+                updated_network_info.network_id = hash_network(&first_info.protocol.network_name).ok();
+                // todo update protocol version
+                updated_network_info.min_pow_score = first_info.protocol.min_pow_score;
+                updated_network_info.bech32_hrp = first_info.protocol.bech32_hrp.clone();
+                updated_network_info.rent_structure = first_info.protocol.rent_structure.clone();
+
+                if updated_network_info.network_id != previous_network_info.network_id
+                    || updated_network_info.bech32_hrp != previous_network_info.bech32_hrp
+                {
+                    let _ = event_sender.send(ClientEvent::NetworkChanged {
+                        network_id: updated_network_info.network_id,
+                        bech32_hrp: updated_network_info.bech32_hrp.clone(),
+                    });
+                }
+
+                network_info.store(Arc::new(updated_network_info));
+            }
+
+            let local_pow = network_info.load().local_pow;
             for (info, node_url) in nodes.iter() {
-                if let Ok(mut client_network_info) = network_info.write() {
-                    client_network_info.network_id = hash_network(&info.protocol.network_name).ok();
-                    // todo update protocol version
-                    client_network_info.min_pow_score = info.protocol.min_pow_score;
-                    client_network_info.bech32_hrp = info.protocol.bech32_hrp.clone();
-                    client_network_info.rent_structure = info.protocol.rent_structure.clone();
-                    if !client_network_info.local_pow {
-                        if info.features.contains(&"PoW".to_string()) {
-                            synced_nodes.insert(node_url.clone());
-                        }
-                    } else {
+                if !local_pow {
+                    if info.features.contains(&"PoW".to_string()) {
                         synced_nodes.insert(node_url.clone());
                     }
+                } else {
+                    synced_nodes.insert(node_url.clone());
                 }
             }
         }
 
         // Update the sync list
         if let Ok(mut sync) = sync.write() {
-            *sync = synced_nodes;
+            *sync = synced_nodes.clone();
+        }
+
+        for node in previous_synced.difference(&synced_nodes) {
+            let _ = event_sender.send(ClientEvent::NodeUnhealthy(node.clone()));
+        }
+        for node in synced_nodes.difference(&previous_synced) {
+            let _ = event_sender.send(ClientEvent::NodeBack(node.clone()));
         }
+        if previous_synced != synced_nodes {
+            let _ = event_sender.send(ClientEvent::SyncedNodesChanged);
+        }
+    }
+
+    /// Monitors the currently verified primary node's health on `check_interval`, using the same
+    /// `tokio::select!` + kill-receiver shutdown pattern as [`Client::start_sync_process`]. Once a node has failed
+    /// `max_consecutive_failures` consecutive checks, the first reachable node from the synced pool is promoted
+    /// into `verified_primary`, so [`Client::get_node`] transparently stops returning the dead node.
+    ///
+    /// Independently of that failover, every node in `all_nodes` is re-probed each `check_interval` and the
+    /// resulting reachable set is written to `healthy_nodes`, so [`Client::healthy_nodes`] reflects live
+    /// reachability for every configured node, not just the primary.
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn start_connectivity_watchdog(
+        runtime: &Runtime,
+        verified_primary: Arc<RwLock<Option<Node>>>,
+        synced_nodes: Arc<RwLock<HashSet<Node>>>,
+        healthy_nodes: Arc<RwLock<HashSet<Node>>>,
+        all_nodes: HashSet<Node>,
+        check_interval: Option<Duration>,
+        max_consecutive_failures: Option<u32>,
+        mut kill: Receiver<()>,
+    ) {
+        let max_consecutive_failures = max_consecutive_failures.unwrap_or(DEFAULT_MAX_CONSECUTIVE_FAILURES);
+        let check_interval = TokioDuration::from_nanos(
+            check_interval
+                .unwrap_or(DEFAULT_CONNECTIVITY_CHECK_INTERVAL)
+                .as_nanos()
+                .try_into()
+                .unwrap_or(DEFAULT_TIPS_INTERVAL),
+        );
+
+        runtime.spawn(async move {
+            let mut consecutive_failures = 0u32;
+
+            loop {
+                tokio::select! {
+                    _ = async {
+                        sleep(check_interval).await;
+
+                        let mut reachable = HashSet::new();
+                        for node in &all_nodes {
+                            if Client::get_node_health(&node.url.to_string()).await.unwrap_or(false) {
+                                reachable.insert(node.clone());
+                            }
+                        }
+                        if let Ok(mut healthy_nodes) = healthy_nodes.write() {
+                            *healthy_nodes = reachable;
+                        }
+
+                        let current = match verified_primary.read().ok().and_then(|primary| primary.clone()) {
+                            Some(current) => current,
+                            None => return,
+                        };
+
+                        if Client::get_node_health(&current.url.to_string()).await.unwrap_or(false) {
+                            consecutive_failures = 0;
+                            return;
+                        }
+
+                        consecutive_failures += 1;
+                        if consecutive_failures < max_consecutive_failures {
+                            return;
+                        }
+
+                        let candidates = synced_nodes.read().map_or(HashSet::new(), |synced| synced.clone());
+                        for candidate in candidates {
+                            if candidate == current {
+                                continue;
+                            }
+                            if Client::get_node_health(&candidate.url.to_string()).await.unwrap_or(false) {
+                                if let Ok(mut primary) = verified_primary.write() {
+                                    *primary = Some(candidate);
+                                }
+                                consecutive_failures = 0;
+                                break;
+                            }
+                        }
+                    } => {}
+                    _ = kill.recv() => {}
+                }
+            }
+        });
+    }
+
+    /// Browses for HORNET/Bee nodes advertising themselves on the local network under [`MDNS_SERVICE_TYPE`] and
+    /// folds healthy, resolved ones into both `nodes` (so they're picked up like any other configured node) and
+    /// `mdns_nodes` (so [`Client::mdns_discovered_nodes`] can report them separately). Advertisements that haven't
+    /// been refreshed within `prune_interval` are dropped from both pools on the same tick, using the same
+    /// `tokio::select!` + kill-receiver shutdown pattern as [`Client::start_sync_process`].
+    #[cfg(feature = "mdns")]
+    pub(crate) fn start_mdns_discovery(
+        runtime: &Runtime,
+        nodes: Arc<RwLock<HashSet<Node>>>,
+        mdns_nodes: Arc<RwLock<HashSet<Node>>>,
+        prune_interval: Duration,
+        mut kill: Receiver<()>,
+    ) -> Result<()> {
+        let daemon = ServiceDaemon::new().map_err(|e| crate::Error::MdnsError(e.to_string()))?;
+        let events = daemon
+            .browse(MDNS_SERVICE_TYPE)
+            .map_err(|e| crate::Error::MdnsError(e.to_string()))?;
+        let poll_interval = TokioDuration::from_millis(500);
+
+        runtime.spawn(async move {
+            let mut last_seen: HashMap<Node, Instant> = HashMap::new();
+
+            loop {
+                tokio::select! {
+                    _ = async {
+                        sleep(poll_interval).await;
+
+                        while let Ok(event) = events.try_recv() {
+                            match event {
+                                ServiceEvent::ServiceResolved(info) => {
+                                    for address in info.get_addresses() {
+                                        let url = format!("http://{}:{}", address, info.get_port());
+                                        if let Ok(node_info) = Client::get_node_info(&url, None).await {
+                                            if node_info.status.is_healthy {
+                                                if let Ok(url) = Url::parse(&url) {
+                                                    let node = Node {
+                                                        url,
+                                                        auth: None,
+                                                        disabled: false,
+                                                    };
+                                                    last_seen.insert(node.clone(), Instant::now());
+                                                    if let Ok(mut nodes) = nodes.write() {
+                                                        nodes.insert(node.clone());
+                                                    }
+                                                    if let Ok(mut mdns_nodes) = mdns_nodes.write() {
+                                                        mdns_nodes.insert(node);
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                ServiceEvent::ServiceRemoved(_, fullname) => {
+                                    last_seen.retain(|node, _| !node.url.as_str().contains(&fullname));
+                                }
+                                _ => {}
+                            }
+                        }
+
+                        let expired: Vec<Node> = last_seen
+                            .iter()
+                            .filter(|(_, seen)| seen.elapsed() > prune_interval)
+                            .map(|(node, _)| node.clone())
+                            .collect();
+                        for node in expired {
+                            last_seen.remove(&node);
+                            if let Ok(mut nodes) = nodes.write() {
+                                nodes.remove(&node);
+                            }
+                            if let Ok(mut mdns_nodes) = mdns_nodes.write() {
+                                mdns_nodes.remove(&node);
+                            }
+                        }
+                    } => {}
+                    _ = kill.recv() => {}
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Nodes discovered via local mDNS service discovery (see [`Client::start_mdns_discovery`]), disabled by
+    /// default and enabled through `ClientBuilder::with_mdns_discovery`.
+    #[cfg(feature = "mdns")]
+    pub async fn mdns_discovered_nodes(&self) -> HashSet<Node> {
+        self.mdns_nodes.read().map_or(HashSet::new(), |nodes| nodes.clone())
     }
 
     /// Get a node candidate from the synced node pool.
+    ///
+    /// Prefers the node most recently confirmed reachable by the connectivity watchdog (see
+    /// [`Client::start_connectivity_watchdog`]) over `node_manager.primary_node`, so callers transparently survive
+    /// a node going down mid-session instead of being handed back the same dead primary. The verified primary is
+    /// only used while it's still a member of `healthy_nodes`; once the watchdog's reachability sweep drops it,
+    /// callers are routed to another healthy node instead of the stale primary.
     pub async fn get_node(&self) -> Result<Node> {
+        #[cfg(not(feature = "wasm"))]
+        {
+            let healthy = self.healthy_nodes.read().map_or(HashSet::new(), |healthy| healthy.clone());
+
+            if let Some(verified) = self.verified_primary_node.read().ok().and_then(|primary| primary.clone()) {
+                if healthy.is_empty() || healthy.contains(&verified) {
+                    return Ok(verified);
+                }
+            }
+            if let Some(healthy_node) = healthy.into_iter().next() {
+                return Ok(healthy_node);
+            }
+        }
         if let Some(primary_node) = &self.node_manager.primary_node {
             return Ok(primary_node.clone());
         }
@@ -261,6 +799,55 @@ impl Client {
         pool.into_iter().next().ok_or(Error::SyncedNodePoolEmpty)
     }
 
+    /// Fans `request` out to up to `self.quorum_size` synced nodes in parallel and returns the response agreed
+    /// on by at least `self.min_quorum_size` of them, so a single stale or malicious node can't taint a
+    /// safety-critical read. Used by [`Client::get_output`], [`Client::get_included_message`],
+    /// [`Client::get_milestone`], and the indexer `*_output_ids` routes when `self.quorum` is enabled.
+    pub(crate) async fn quorum_query<'a, T, F, Fut>(&'a self, request: F) -> Result<T>
+    where
+        T: PartialEq + Clone,
+        F: Fn(Node) -> Fut,
+        Fut: std::future::Future<Output = Result<T>> + 'a,
+    {
+        let candidates: Vec<Node> = self
+            .node_manager
+            .synced_nodes
+            .read()
+            .map_or_else(|_| Vec::new(), |synced| synced.iter().take(self.quorum_size).cloned().collect());
+
+        if candidates.len() < self.min_quorum_size {
+            return Err(Error::QuorumThreshold {
+                min_quorum_size: self.min_quorum_size,
+                got: candidates.len(),
+            });
+        }
+
+        let responses: Vec<T> = futures::future::join_all(candidates.into_iter().map(&request))
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
+
+        let mut tally: Vec<(T, usize)> = Vec::new();
+        for response in responses {
+            match tally.iter_mut().find(|(existing, _)| *existing == response) {
+                Some((_, count)) => *count += 1,
+                None => tally.push((response, 1)),
+            }
+        }
+
+        let best_count = tally.iter().map(|(_, count)| *count).max().unwrap_or(0);
+
+        tally
+            .into_iter()
+            .find(|(_, count)| *count >= self.min_quorum_size)
+            .map(|(value, _)| value)
+            .ok_or(Error::QuorumThreshold {
+                min_quorum_size: self.min_quorum_size,
+                got: best_count,
+            })
+    }
+
     /// Gets the miner to use based on the PoW setting
     pub async fn get_pow_provider(&self) -> ClientMiner {
         ClientMinerBuilder::new()
@@ -271,7 +858,7 @@ impl Client {
     /// Gets the network related information such as network_id and min_pow_score
     /// and if it's the default one, sync it first.
     pub async fn get_network_info(&self) -> Result<NetworkInfo> {
-        let not_synced = self.network_info.read().map_or(true, |info| info.network_id.is_none());
+        let not_synced = self.network_info.load().network_id.is_none();
 
         // For WASM we don't have the node syncing process, which updates the network_info every 60 seconds, but the PoW
         // difficulty or the byte cost could change via a milestone, so we request the nodeinfo every time, so we don't
@@ -279,18 +866,13 @@ impl Client {
         if not_synced || cfg!(feature = "wasm") {
             let info = self.get_info().await?.nodeinfo;
             let network_id = hash_network(&info.protocol.network_name).ok();
-            {
-                let mut client_network_info = self.network_info.write().map_err(|_| crate::Error::PoisonError)?;
-                client_network_info.network_id = network_id;
-                client_network_info.min_pow_score = info.protocol.min_pow_score;
-                client_network_info.bech32_hrp = info.protocol.bech32_hrp;
-            }
+            let mut updated_network_info = (*self.network_info.load_full()).clone();
+            updated_network_info.network_id = network_id;
+            updated_network_info.min_pow_score = info.protocol.min_pow_score;
+            updated_network_info.bech32_hrp = info.protocol.bech32_hrp;
+            self.network_info.store(Arc::new(updated_network_info));
         }
-        let res = self
-            .network_info
-            .read()
-            .map_or(NetworkInfo::default(), |info| info.clone());
-        Ok(res)
+        Ok((*self.network_info.load_full()).clone())
     }
 
     /// Gets the network id of the node we're connecting to.
@@ -313,16 +895,12 @@ impl Client {
 
     /// returns the tips interval
     pub async fn get_tips_interval(&self) -> u64 {
-        self.network_info
-            .read()
-            .map_or(DEFAULT_TIPS_INTERVAL, |info| info.tips_interval)
+        self.network_info.load().tips_interval
     }
 
     /// returns if local pow should be used or not
     pub async fn get_local_pow(&self) -> bool {
-        self.network_info
-            .read()
-            .map_or(NetworkInfo::default().local_pow, |info| info.local_pow)
+        self.network_info.load().local_pow
     }
 
     /// returns the byte cost configuration
@@ -345,11 +923,15 @@ impl Client {
 
     /// returns the fallback_to_local_pow
     pub async fn get_fallback_to_local_pow(&self) -> bool {
-        self.network_info
-            .read()
-            .map_or(NetworkInfo::default().fallback_to_local_pow, |info| {
-                info.fallback_to_local_pow
-            })
+        self.network_info.load().fallback_to_local_pow
+    }
+
+    /// Returns every configured node the connectivity watchdog most recently found reachable, as of its last
+    /// `ClientBuilder::with_node_health_interval` tick. Unlike [`Client::unsynced_nodes`], this reflects plain
+    /// reachability (`get_node_health`), independent of whether a node has also been folded into the synced pool.
+    #[cfg(not(feature = "wasm"))]
+    pub async fn healthy_nodes(&self) -> HashSet<Node> {
+        self.healthy_nodes.read().map_or(HashSet::new(), |healthy| healthy.clone())
     }
 
     /// returns the unsynced nodes.
@@ -364,6 +946,14 @@ impl Client {
         })
     }
 
+    /// Subscribes to this client's connectivity/network event stream. Each call returns an independent
+    /// [`Receiver`], so multiple observers (e.g. a UI and a wallet layer) can subscribe without stealing events
+    /// from one another. Events are emitted by [`Client::sync_nodes`]; see [`ClientEvent`].
+    #[cfg(not(feature = "wasm"))]
+    pub fn event_receiver(&self) -> Receiver<ClientEvent> {
+        self.event_sender.subscribe()
+    }
+
     ///////////////////////////////////////////////////////////////////////
     // MQTT API
     //////////////////////////////////////////////////////////////////////
@@ -380,6 +970,26 @@ impl Client {
         self.mqtt_event_channel.1.clone()
     }
 
+    /// Subscribes to the `messages/{messageId}/metadata` topic and forwards every parsed event over an unbounded
+    /// channel, so callers like [`Client::retry_until_included`] can await a pushed inclusion update instead of
+    /// only polling for it.
+    #[cfg(feature = "mqtt")]
+    async fn subscribe_message_metadata(
+        &mut self,
+        message_id: &MessageId,
+    ) -> Result<UnboundedReceiver<crate::node_api::mqtt::MessageMetadataEvent>> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let topic = Topic::try_new(format!("messages/{}/metadata", message_id))?;
+
+        self.subscriber().with_topics(vec![topic]).subscribe(move |event| {
+            if let Ok(metadata) = serde_json::from_str(&event.payload) {
+                let _ = tx.send(metadata);
+            }
+        })?;
+
+        Ok(rx)
+    }
+
     //////////////////////////////////////////////////////////////////////
     // Node core API
     //////////////////////////////////////////////////////////////////////
@@ -478,6 +1088,13 @@ impl Client {
         crate::node_api::core::routes::post_message_json(self, message).await
     }
 
+    /// Posts an already-built message, e.g. one returned by [`Client::finish_message_builder`] or mined offline,
+    /// without re-running proof-of-work. Equivalent to [`Client::post_message`]; kept as an explicitly-named
+    /// companion to `finish_message_builder` for offline-signing and custom reattach flows.
+    pub async fn post_message_raw(&self, message: &Message) -> Result<MessageId> {
+        self.post_message(message).await
+    }
+
     /// GET /api/v2/messages/{messageID} endpoint
     /// Consume the builder and find a message by its identifer. This method returns the given message object.
     pub async fn get_message_data(&self, message_id: &MessageId) -> Result<Message> {
@@ -505,6 +1122,14 @@ impl Client {
     /// GET /api/v2/outputs/{outputId} endpoint
     /// Find an output by its transaction_id and corresponding output_index.
     pub async fn get_output(&self, output_id: &OutputId) -> Result<OutputResponse> {
+        if self.quorum {
+            let output_id = *output_id;
+            return self
+                .quorum_query(move |node| async move {
+                    crate::node_api::core::routes::get_output_from_node(self, &node, &output_id).await
+                })
+                .await;
+        }
         crate::node_api::core::routes::get_output(self, output_id).await
     }
 
@@ -516,6 +1141,13 @@ impl Client {
     /// GET /api/v2/milestones/{index} endpoint
     /// Get the milestone by the given index.
     pub async fn get_milestone(&self, index: u32) -> Result<MilestoneResponse> {
+        if self.quorum {
+            return self
+                .quorum_query(move |node| async move {
+                    crate::node_api::core::routes::get_milestone_from_node(self, &node, index).await
+                })
+                .await;
+        }
         crate::node_api::core::routes::get_milestone(self, index).await
     }
 
@@ -546,6 +1178,14 @@ impl Client {
     /// GET /api/v2/transactions/{transactionId}/included-message
     /// Returns the included message of the transaction.
     pub async fn get_included_message(&self, transaction_id: &TransactionId) -> Result<Message> {
+        if self.quorum {
+            let transaction_id = *transaction_id;
+            return self
+                .quorum_query(move |node| async move {
+                    crate::node_api::core::routes::get_included_message_from_node(self, &node, &transaction_id).await
+                })
+                .await;
+        }
         crate::node_api::core::routes::get_included_message(self, transaction_id).await
     }
 
@@ -555,11 +1195,32 @@ impl Client {
 
     /// api/plugins/indexer/v1/basic-outputs
     pub async fn output_ids(&self, query_parameters: Vec<QueryParameter>) -> Result<Vec<OutputId>> {
+        if self.quorum {
+            return self
+                .quorum_query(move |node| {
+                    let query_parameters = query_parameters.clone();
+                    async move {
+                        crate::node_api::indexer::routes::output_ids_from_node(self, &node, query_parameters).await
+                    }
+                })
+                .await;
+        }
         crate::node_api::indexer::routes::output_ids(self, query_parameters).await
     }
 
     /// api/plugins/indexer/v1/aliases
     pub async fn aliases_output_ids(&self, query_parameters: Vec<QueryParameter>) -> Result<Vec<OutputId>> {
+        if self.quorum {
+            return self
+                .quorum_query(move |node| {
+                    let query_parameters = query_parameters.clone();
+                    async move {
+                        crate::node_api::indexer::routes::aliases_output_ids_from_node(self, &node, query_parameters)
+                            .await
+                    }
+                })
+                .await;
+        }
         crate::node_api::indexer::routes::aliases_output_ids(self, query_parameters).await
     }
 
@@ -570,6 +1231,17 @@ impl Client {
 
     /// api/plugins/indexer/v1/nfts
     pub async fn nfts_output_ids(&self, query_parameters: Vec<QueryParameter>) -> Result<Vec<OutputId>> {
+        if self.quorum {
+            return self
+                .quorum_query(move |node| {
+                    let query_parameters = query_parameters.clone();
+                    async move {
+                        crate::node_api::indexer::routes::nfts_output_ids_from_node(self, &node, query_parameters)
+                            .await
+                    }
+                })
+                .await;
+        }
         crate::node_api::indexer::routes::nfts_output_ids(self, query_parameters).await
     }
 
@@ -580,6 +1252,17 @@ impl Client {
 
     /// api/plugins/indexer/v1/foundries
     pub async fn foundries_output_ids(&self, query_parameters: Vec<QueryParameter>) -> Result<Vec<OutputId>> {
+        if self.quorum {
+            return self
+                .quorum_query(move |node| {
+                    let query_parameters = query_parameters.clone();
+                    async move {
+                        crate::node_api::indexer::routes::foundries_output_ids_from_node(self, &node, query_parameters)
+                            .await
+                    }
+                })
+                .await;
+        }
         crate::node_api::indexer::routes::foundries_output_ids(self, query_parameters).await
     }
 
@@ -629,6 +1312,94 @@ impl Client {
         crate::node_api::core::get_outputs(self, input_ids).await
     }
 
+    /// Independently re-checks a built transaction essence before it's signed and posted, rather than trusting
+    /// [`Client::message`]'s builder: asserts every `(bech32_address, amount)` pair in `requested_outputs` appears
+    /// among `essence`'s outputs, that summed input amounts minus summed output amounts equals `expected_fee`, and
+    /// that any output not accounted for by `requested_outputs` (i.e. an implicit remainder/change output) pays an
+    /// address `signer` controls. Fetches the inputs' amounts from the node, so this is opt-in rather than run
+    /// automatically by the builder.
+    pub async fn verify_transaction_essence(
+        &self,
+        essence: &TransactionEssence,
+        signer: &SignerHandle,
+        requested_outputs: &[(String, u64)],
+        expected_fee: u64,
+    ) -> Result<()> {
+        let TransactionEssence::Regular(essence) = essence;
+
+        // Tracked by index (not just amount) so that an output with the wrong address can never be mistaken for
+        // having satisfied a request merely because its amount happens to collide with a requested one; any such
+        // output still falls through to the remainder/controlled-address check below.
+        let mut requested_output_indices: HashSet<usize> = HashSet::new();
+        for (bech32_address, amount) in requested_outputs {
+            let (_, requested_address) = parse_bech32_address(bech32_address)?;
+            let matched_index = essence.outputs().iter().enumerate().find(|(index, output)| {
+                !requested_output_indices.contains(index)
+                    && output.amount() == *amount
+                    && output
+                        .unlock_conditions()
+                        .and_then(|unlock_conditions| unlock_conditions.address())
+                        .map_or(false, |address_condition| *address_condition.address() == requested_address)
+            });
+            match matched_index {
+                Some((index, _)) => {
+                    requested_output_indices.insert(index);
+                }
+                None => return Err(Error::TransactionEssenceMismatch(bech32_address.clone(), *amount)),
+            }
+        }
+
+        let input_ids = essence
+            .inputs()
+            .iter()
+            .map(|input| match input {
+                Input::Utxo(input) => *input.output_id(),
+                _ => unreachable!(),
+            })
+            .collect();
+        let input_outputs = self.get_outputs(input_ids).await?;
+
+        let mut total_inputs = 0u64;
+        for output_resp in &input_outputs {
+            let (amount, _) = ClientMessageBuilder::get_output_amount_and_address(&output_resp.output, None)?;
+            total_inputs += amount;
+        }
+        let total_outputs: u64 = essence.outputs().iter().map(bee_message::output::Output::amount).sum();
+        let actual_fee = total_inputs
+            .checked_sub(total_outputs)
+            .ok_or(Error::TransactionEssenceUnderfunded(total_inputs, total_outputs))?;
+        if actual_fee != expected_fee {
+            return Err(Error::UnexpectedTransactionEssenceFee(expected_fee, actual_fee));
+        }
+
+        let remainder_outputs = essence
+            .outputs()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !requested_output_indices.contains(index))
+            .map(|(_, output)| output);
+        for output in remainder_outputs {
+            let remainder_address = output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.address())
+                .map(|address_condition| *address_condition.address());
+            if let Some(remainder_address) = remainder_address {
+                let controlled = self
+                    .get_addresses(signer)
+                    .with_range(DEFAULT_CHANGE_ADDRESS_SEARCH_RANGE)
+                    .get_raw()
+                    .await?
+                    .into_iter()
+                    .any(|address| address == remainder_address);
+                if !controlled {
+                    return Err(Error::UncontrolledRemainderAddress(remainder_address.to_bech32("iota")));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// A generic send function for easily sending transaction or tagged data messages.
     pub fn message(&self) -> ClientMessageBuilder<'_> {
         ClientMessageBuilder::new(self)
@@ -640,23 +1411,24 @@ impl Client {
     }
 
     /// Find all messages by provided message IDs.
+    ///
+    /// Fetches are dispatched up to `max_parallel_requests` at a time (see `ClientBuilder::with_max_parallel_requests`)
+    /// instead of one after another, while still returning one [`Message`] per de-duplicated id in the order the
+    /// ids were first seen in `message_ids`.
     pub async fn find_messages(&self, message_ids: &[MessageId]) -> Result<Vec<Message>> {
-        let mut messages = Vec::new();
-
-        // Use a `HashSet` to prevent duplicate message_ids.
-        let mut message_ids_to_query = HashSet::<MessageId>::new();
-
-        // Collect the `MessageId` in the HashSet.
-        for message_id in message_ids {
-            message_ids_to_query.insert(message_id.to_owned());
-        }
+        // Drop duplicate message_ids, keeping the order they were first seen in.
+        let mut seen = HashSet::<MessageId>::new();
+        let message_ids_to_query: Vec<MessageId> = message_ids
+            .iter()
+            .filter(|message_id| seen.insert(**message_id))
+            .copied()
+            .collect();
 
-        // Use `get_message_data()` API to get the `Message`.
-        for message_id in message_ids_to_query {
-            let message = self.get_message_data(&message_id).await?;
-            messages.push(message);
-        }
-        Ok(messages)
+        futures::stream::iter(message_ids_to_query)
+            .map(|message_id| async move { self.get_message_data(&message_id).await })
+            .buffered(self.max_parallel_requests)
+            .try_collect()
+            .await
     }
 
     /// Retries (promotes or reattaches) a message for provided message id. Message should only be
@@ -674,77 +1446,234 @@ impl Client {
     }
 
     /// Retries (promotes or reattaches) a message for provided message id until it's included (referenced by a
-    /// milestone). Default interval is 5 seconds and max attempts is 40. Returns the included message at first position
-    /// and additional reattached messages
+    /// milestone). Polling follows `policy` (defaulting to a fixed 5 second interval, up to 40 attempts, if `None`).
+    /// Returns the included message at first position and additional reattached messages.
+    ///
+    /// When the `mqtt` feature is enabled, this subscribes to the message's metadata topic and reacts to a pushed
+    /// inclusion update as soon as it arrives, only falling back to the polling interval below while no such event
+    /// has come in yet.
     pub async fn retry_until_included(
-        &self,
+        &mut self,
         message_id: &MessageId,
-        interval: Option<u64>,
-        max_attempts: Option<u64>,
+        policy: Option<RetryPolicy>,
     ) -> Result<Vec<(MessageId, Message)>> {
         log::debug!("[retry_until_included]");
+        let policy = policy.unwrap_or_default();
         // Attachments of the Message to check inclusion state
         let mut message_ids = vec![*message_id];
         // Reattached Messages that get returned
         let mut messages_with_id = Vec::new();
-        for _ in 0..max_attempts.unwrap_or(40) {
-            #[cfg(feature = "wasm")]
+        #[cfg(feature = "mqtt")]
+        let mut mqtt_metadata_rx = self.subscribe_message_metadata(message_id).await.ok();
+        let start = instant::Instant::now();
+
+        for attempt in 0..policy.max_attempts {
+            if policy.timeout.map_or(false, |timeout| start.elapsed() >= timeout) {
+                break;
+            }
+            let wait = policy.interval_for_attempt(attempt as u32);
+            if let RetryStep::Done(result) = self
+                .retry_until_included_step(
+                    message_id,
+                    &mut message_ids,
+                    &mut messages_with_id,
+                    #[cfg(feature = "mqtt")]
+                    &mut mqtt_metadata_rx,
+                    wait,
+                )
+                .await?
             {
-                TimeoutFuture::new((interval.unwrap_or(5) * 1000).try_into().unwrap()).await;
+                return Ok(result);
             }
-            #[cfg(not(feature = "wasm"))]
-            sleep(Duration::from_secs(interval.unwrap_or(5))).await;
-            // Check inclusion state for each attachment
-            let message_ids_len = message_ids.len();
-            let mut conflicting = false;
-            for (index, msg_id) in message_ids.clone().iter().enumerate() {
-                let message_metadata = self.get_message_metadata(msg_id).await?;
-                if let Some(inclusion_state) = message_metadata.ledger_inclusion_state {
-                    match inclusion_state {
-                        LedgerInclusionStateDto::Included | LedgerInclusionStateDto::NoTransaction => {
-                            // if original message, request it so we can return it on first position
-                            if message_id == msg_id {
-                                let mut included_and_reattached_messages =
-                                    vec![(*message_id, self.get_message_data(message_id).await?)];
-                                included_and_reattached_messages.extend(messages_with_id);
-                                return Ok(included_and_reattached_messages);
-                            } else {
-                                // Move included message to first position
-                                messages_with_id.rotate_left(index);
-                                return Ok(messages_with_id);
-                            }
-                        }
-                        // only set it as conflicting here and don't return, because another reattached message could
-                        // have the included transaction
-                        LedgerInclusionStateDto::Conflicting => conflicting = true,
-                    };
+        }
+        Err(Error::TangleInclusionError(message_id.to_string()))
+    }
+
+    /// Same polling loop as [`Client::retry_until_included`], but returns a [`futures::Stream`] of [`RetryProgress`]
+    /// events instead of blocking until the final aggregated [`Vec`]. Lets a UI show reattachment/promotion/conflict
+    /// progress as it happens rather than only the end result.
+    pub fn retry_until_included_stream<'a>(
+        &'a mut self,
+        message_id: &'a MessageId,
+        policy: Option<RetryPolicy>,
+    ) -> impl futures::Stream<Item = Result<RetryProgress>> + 'a {
+        struct StreamState<'a> {
+            client: &'a mut Client,
+            message_ids: Vec<MessageId>,
+            messages_with_id: Vec<(MessageId, Message)>,
+            #[cfg(feature = "mqtt")]
+            mqtt_metadata_rx: Option<UnboundedReceiver<crate::node_api::mqtt::MessageMetadataEvent>>,
+            #[cfg(feature = "mqtt")]
+            subscribed: bool,
+            pending: std::collections::VecDeque<RetryProgress>,
+            attempt: u64,
+            start: instant::Instant,
+            policy: RetryPolicy,
+            done: bool,
+        }
+
+        let state = StreamState {
+            client: self,
+            message_ids: vec![*message_id],
+            messages_with_id: Vec::new(),
+            #[cfg(feature = "mqtt")]
+            mqtt_metadata_rx: None,
+            #[cfg(feature = "mqtt")]
+            subscribed: false,
+            pending: std::collections::VecDeque::new(),
+            attempt: 0,
+            start: instant::Instant::now(),
+            policy: policy.unwrap_or_default(),
+            done: false,
+        };
+
+        futures::stream::unfold(state, move |mut state| async move {
+            if state.done {
+                return None;
+            }
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Some((Ok(event), state));
                 }
-                // Only reattach or promote latest attachment of the message
-                if index == message_ids_len - 1 {
-                    if message_metadata.should_promote.unwrap_or(false) {
-                        // Safe to unwrap since we iterate over it
-                        self.promote_unchecked(message_ids.last().unwrap()).await?;
-                    } else if message_metadata.should_reattach.unwrap_or(false) {
-                        // Safe to unwrap since we iterate over it
-                        let reattached = self.reattach_unchecked(message_ids.last().unwrap()).await?;
-                        message_ids.push(reattached.0);
-                        messages_with_id.push(reattached);
+
+                #[cfg(feature = "mqtt")]
+                if !state.subscribed {
+                    state.mqtt_metadata_rx = state.client.subscribe_message_metadata(message_id).await.ok();
+                    state.subscribed = true;
+                }
+
+                if state.attempt >= state.policy.max_attempts
+                    || state
+                        .policy
+                        .timeout
+                        .map_or(false, |timeout| state.start.elapsed() >= timeout)
+                {
+                    state.done = true;
+                    return Some((Err(Error::TangleInclusionError(message_id.to_string())), state));
+                }
+
+                let wait = state.policy.interval_for_attempt(state.attempt as u32);
+                state.attempt += 1;
+
+                let step = state
+                    .client
+                    .retry_until_included_step(
+                        message_id,
+                        &mut state.message_ids,
+                        &mut state.messages_with_id,
+                        #[cfg(feature = "mqtt")]
+                        &mut state.mqtt_metadata_rx,
+                        wait,
+                    )
+                    .await;
+
+                match step {
+                    Ok(RetryStep::Done(result)) => {
+                        state.done = true;
+                        return Some((Ok(RetryProgress::Included(result)), state));
+                    }
+                    Ok(RetryStep::Continue(events)) => state.pending.extend(events),
+                    Err(err) => {
+                        state.done = true;
+                        return Some((Err(err), state));
                     }
                 }
             }
-            // After we checked all our reattached messages, check if the transaction got reattached in another message
-            // and confirmed
-            if conflicting {
-                let message = self.get_message_data(message_id).await?;
-                if let Some(Payload::Transaction(transaction_payload)) = message.payload() {
-                    let included_message = self.get_included_message(&transaction_payload.id()).await?;
-                    let mut included_and_reattached_messages = vec![(included_message.id(), included_message)];
-                    included_and_reattached_messages.extend(messages_with_id);
-                    return Ok(included_and_reattached_messages);
+        })
+    }
+
+    /// One polling iteration shared by [`Client::retry_until_included`] and
+    /// [`Client::retry_until_included_stream`]: waits `wait` (racing a pushed mqtt metadata event when subscribed),
+    /// then checks the inclusion state of every known attachment, promoting or reattaching the latest one if
+    /// needed. Returns [`RetryStep::Done`] once inclusion (or an unambiguous conflicting-but-reattached-elsewhere
+    /// resolution) is found.
+    async fn retry_until_included_step(
+        &mut self,
+        message_id: &MessageId,
+        message_ids: &mut Vec<MessageId>,
+        messages_with_id: &mut Vec<(MessageId, Message)>,
+        #[cfg(feature = "mqtt")] mqtt_metadata_rx: &mut Option<UnboundedReceiver<crate::node_api::mqtt::MessageMetadataEvent>>,
+        wait: Duration,
+    ) -> Result<RetryStep> {
+        #[cfg(feature = "wasm")]
+        {
+            TimeoutFuture::new(wait.as_millis() as u32).await;
+        }
+        #[cfg(not(feature = "wasm"))]
+        {
+            #[cfg(feature = "mqtt")]
+            if let Some(rx) = mqtt_metadata_rx.as_mut() {
+                // Race the poll interval against a pushed metadata event; either one is enough to move on to
+                // the inclusion-state check below instead of sleeping out the full interval.
+                tokio::select! {
+                    _ = sleep(wait) => {}
+                    _ = rx.recv() => {}
                 }
+            } else {
+                sleep(wait).await;
             }
+            #[cfg(not(feature = "mqtt"))]
+            sleep(wait).await;
         }
-        Err(Error::TangleInclusionError(message_id.to_string()))
+
+        let mut events = Vec::new();
+        // Check inclusion state for each attachment
+        let message_ids_len = message_ids.len();
+        let mut conflicting = false;
+        for (index, msg_id) in message_ids.clone().iter().enumerate() {
+            let message_metadata = self.get_message_metadata(msg_id).await?;
+            if let Some(inclusion_state) = message_metadata.ledger_inclusion_state {
+                match inclusion_state {
+                    LedgerInclusionStateDto::Included | LedgerInclusionStateDto::NoTransaction => {
+                        // if original message, request it so we can return it on first position
+                        if message_id == msg_id {
+                            let mut included_and_reattached_messages =
+                                vec![(*message_id, self.get_message_data(message_id).await?)];
+                            included_and_reattached_messages.extend(messages_with_id.clone());
+                            return Ok(RetryStep::Done(included_and_reattached_messages));
+                        } else {
+                            // Move included message to first position
+                            let mut rotated = messages_with_id.clone();
+                            rotated.rotate_left(index);
+                            return Ok(RetryStep::Done(rotated));
+                        }
+                    }
+                    // only set it as conflicting here and don't return, because another reattached message could
+                    // have the included transaction
+                    LedgerInclusionStateDto::Conflicting => {
+                        conflicting = true;
+                        events.push(RetryProgress::Conflicting(*msg_id));
+                    }
+                };
+            }
+            // Only reattach or promote latest attachment of the message
+            if index == message_ids_len - 1 {
+                if message_metadata.should_promote.unwrap_or(false) {
+                    // Safe to unwrap since we iterate over it
+                    self.promote_unchecked(message_ids.last().unwrap()).await?;
+                    events.push(RetryProgress::Promoted(*message_ids.last().unwrap()));
+                } else if message_metadata.should_reattach.unwrap_or(false) {
+                    // Safe to unwrap since we iterate over it
+                    let reattached = self.reattach_unchecked(message_ids.last().unwrap()).await?;
+                    events.push(RetryProgress::Reattached(reattached.0));
+                    message_ids.push(reattached.0);
+                    messages_with_id.push(reattached);
+                }
+            }
+        }
+        // After we checked all our reattached messages, check if the transaction got reattached in another message
+        // and confirmed
+        if conflicting {
+            let message = self.get_message_data(message_id).await?;
+            if let Some(Payload::Transaction(transaction_payload)) = message.payload() {
+                let included_message = self.get_included_message(&transaction_payload.id()).await?;
+                let mut included_and_reattached_messages = vec![(included_message.id(), included_message)];
+                included_and_reattached_messages.extend(messages_with_id.clone());
+                return Ok(RetryStep::Done(included_and_reattached_messages));
+            }
+        }
+
+        Ok(RetryStep::Continue(events))
     }
 
     /// Function to consolidate all funds from a range of addresses to the address with the lowest index in that range
@@ -759,11 +1688,24 @@ impl Client {
     }
 
     /// Function to find inputs from addresses for a provided amount (useful for offline signing), ignoring outputs with
-    /// additional unlock conditions
+    /// additional unlock conditions. Uses [`InputSelectionStrategy::Greedy`]; see [`Client::find_inputs_with_strategy`]
+    /// to pick a different strategy, e.g. branch-and-bound to avoid a dust remainder output.
     pub async fn find_inputs(&self, addresses: Vec<String>, amount: u64) -> Result<Vec<UtxoInput>> {
+        self.find_inputs_with_strategy(addresses, amount, InputSelectionStrategy::default())
+            .await
+    }
+
+    /// Same as [`Client::find_inputs`], but lets the caller pick the [`InputSelectionStrategy`] used to cover
+    /// `amount`.
+    pub async fn find_inputs_with_strategy(
+        &self,
+        addresses: Vec<String>,
+        amount: u64,
+        strategy: InputSelectionStrategy,
+    ) -> Result<Vec<UtxoInput>> {
         // Get outputs from node and select inputs
         let mut available_outputs = Vec::new();
-        for address in addresses {
+        for address in &addresses {
             available_outputs.extend_from_slice(
                 &self
                     .get_address()
@@ -790,28 +1732,121 @@ impl Client {
             ));
         }
         basic_outputs.sort_by(|l, r| r.1.cmp(&l.1));
+        // Max inputs is 128
+        basic_outputs.truncate(INPUT_COUNT_MAX.into());
+
+        let (selected_inputs, total_already_spent) = match strategy {
+            InputSelectionStrategy::Greedy => Self::select_inputs_greedy(&basic_outputs, amount),
+            InputSelectionStrategy::BranchAndBound { cost_of_change } => {
+                let values: Vec<u64> = basic_outputs.iter().map(|(_, amount)| *amount).collect();
+                let upper = amount.saturating_add(cost_of_change);
+                match BranchAndBoundSearch::new(&values, amount, upper).run() {
+                    Some(indices) => {
+                        let total = indices.iter().map(|&i| basic_outputs[i].1).sum();
+                        let inputs = indices.into_iter().map(|i| basic_outputs[i].0.clone()).collect();
+                        (inputs, total)
+                    }
+                    None => Self::select_inputs_greedy(&basic_outputs, amount),
+                }
+            }
+        };
+
+        if total_already_spent < amount {
+            // The query above excludes outputs still behind a timelock/expiration condition, so a shortfall here
+            // might just mean the outstanding amount is sitting in one of those rather than genuinely missing.
+            // Pull them in and let `check_spendable_or_predict_wait` turn that into a predicted wait instead of a
+            // flat insufficient-funds error, the way a caller doing a plain balance sum never could.
+            let mut candidate_inputs = Vec::new();
+            for address in &addresses {
+                let blocked_outputs = self
+                    .get_address()
+                    .outputs(vec![
+                        QueryParameter::Address(address.to_string()),
+                        QueryParameter::HasStorageDepositReturnCondition(false),
+                    ])
+                    .await?;
+
+                for output_resp in blocked_outputs {
+                    let output = bee_block::output::Output::try_from(&output_resp.output)?;
+                    let utxo_input = UtxoInput::new(
+                        TransactionId::from_str(&output_resp.transaction_id)?,
+                        output_resp.output_index,
+                    )?;
+                    candidate_inputs.push((
+                        utxo_input,
+                        crate::secret::types::InputSigningData {
+                            output,
+                            chain: None,
+                            bech32_address: address.clone(),
+                        },
+                    ));
+                }
+            }
+
+            let current_time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_secs() as u32;
+
+            let signing_data: Vec<_> = candidate_inputs.iter().map(|(_, data)| data.clone()).collect();
+            crate::api::block_builder::input_selection::helpers::check_spendable_or_predict_wait(
+                &signing_data,
+                amount,
+                current_time,
+            )?;
+
+            // `check_spendable_or_predict_wait` returned `Ok(())`: `candidate_inputs` (queried without the
+            // timelock/expiration exclusion applied to `basic_outputs` above) holds enough already-spendable value
+            // on its own, so select from it directly instead of reporting a balance shortfall that isn't real.
+            let spendable_outputs: Vec<(UtxoInput, u64)> = candidate_inputs
+                .iter()
+                .filter(|(_, data)| {
+                    crate::api::block_builder::input_selection::helpers::blocked_until(&data.output, current_time)
+                        .is_none()
+                })
+                .map(|(utxo_input, data)| (utxo_input.clone(), data.output.amount()))
+                .collect();
+
+            let (selected_inputs, total_already_spent) = match strategy {
+                InputSelectionStrategy::Greedy => Self::select_inputs_greedy(&spendable_outputs, amount),
+                InputSelectionStrategy::BranchAndBound { cost_of_change } => {
+                    let values: Vec<u64> = spendable_outputs.iter().map(|(_, amount)| *amount).collect();
+                    let upper = amount.saturating_add(cost_of_change);
+                    match BranchAndBoundSearch::new(&values, amount, upper).run() {
+                        Some(indices) => {
+                            let total = indices.iter().map(|&i| spendable_outputs[i].1).sum();
+                            let inputs = indices.into_iter().map(|i| spendable_outputs[i].0.clone()).collect();
+                            (inputs, total)
+                        }
+                        None => Self::select_inputs_greedy(&spendable_outputs, amount),
+                    }
+                }
+            };
+
+            if total_already_spent < amount {
+                return Err(crate::Error::NotEnoughBalance(total_already_spent, amount));
+            }
+
+            return Ok(selected_inputs);
+        }
+
+        Ok(selected_inputs)
+    }
 
+    /// Accumulates `candidates` (already sorted largest-value-first) until `amount` is covered, or all candidates
+    /// are exhausted. Returns the selected inputs and their summed value.
+    fn select_inputs_greedy(candidates: &[(UtxoInput, u64)], amount: u64) -> (Vec<UtxoInput>, u64) {
         let mut total_already_spent = 0;
         let mut selected_inputs = Vec::new();
-        for (_offset, output_wrapper) in basic_outputs
-            .into_iter()
-            // Max inputs is 128
-            .take(INPUT_COUNT_MAX.into())
-            .enumerate()
-        {
+        for (input, value) in candidates {
             // Break if we have enough funds and don't create dust for the remainder
-            if total_already_spent == amount || total_already_spent >= amount {
+            if total_already_spent >= amount {
                 break;
             }
-            selected_inputs.push(output_wrapper.0.clone());
-            total_already_spent += output_wrapper.1;
-        }
-
-        if total_already_spent < amount {
-            return Err(crate::Error::NotEnoughBalance(total_already_spent, amount));
+            selected_inputs.push(input.clone());
+            total_already_spent += value;
         }
-
-        Ok(selected_inputs)
+        (selected_inputs, total_already_spent)
     }
 
     /// Find all outputs based on the requests criteria. This method will try to query multiple nodes if
@@ -821,20 +1856,25 @@ impl Client {
             crate::node_api::core::get_outputs(self, outputs.iter().map(|output| *output.output_id()).collect())
                 .await?;
 
-        // Use `get_address()` API to get the address outputs first,
-        // then collect the `UtxoInput` in the HashSet.
-        for address in addresses {
-            // Get output ids of outputs that can be controlled by this address without further unlock constraints
-            let address_outputs = self
-                .get_address()
-                .outputs(vec![
-                    QueryParameter::Address(address.to_string()),
-                    QueryParameter::HasExpirationCondition(false),
-                    QueryParameter::HasTimelockCondition(false),
-                    QueryParameter::HasStorageDepositReturnCondition(false),
-                ])
-                .await?;
-            output_metadata.extend(address_outputs.into_iter());
+        // Get output ids of outputs that can be controlled by each address without further unlock constraints,
+        // up to `max_parallel_requests` addresses at a time instead of one after another.
+        let address_outputs: Vec<Vec<OutputResponse>> = futures::stream::iter(addresses)
+            .map(|address| async move {
+                self.get_address()
+                    .outputs(vec![
+                        QueryParameter::Address(address.to_string()),
+                        QueryParameter::HasExpirationCondition(false),
+                        QueryParameter::HasTimelockCondition(false),
+                        QueryParameter::HasStorageDepositReturnCondition(false),
+                    ])
+                    .await
+            })
+            .buffered(self.max_parallel_requests)
+            .try_collect()
+            .await?;
+
+        for outputs in address_outputs {
+            output_metadata.extend(outputs);
         }
 
         Ok(output_metadata.to_vec())
@@ -851,30 +1891,40 @@ impl Client {
         }
     }
 
+    /// Builds (and, unless `local_pow` is disabled by remote PoW, mines) a message on top of `parents`, falling back
+    /// to the current tips when `parents` is `None`. Factors out the tips-fetch/sort/dedup, [`MessageBuilder`]
+    /// construction, and nonce-provider wiring that [`Client::reattach_unchecked`] and [`Client::promote_unchecked`]
+    /// used to duplicate, and lets a caller inject its own parents or reuse a pre-mined message for offline signing
+    /// or a custom reattach flow.
+    pub async fn finish_message_builder(
+        &self,
+        parents: Option<Vec<MessageId>>,
+        payload: Option<Payload>,
+    ) -> Result<Message> {
+        let mut tips = match parents {
+            Some(parents) => parents,
+            None => self.get_tips().await?,
+        };
+        tips.sort_unstable_by_key(|a| a.pack_to_vec());
+        tips.dedup();
+
+        let min_pow_score = self.get_min_pow_score().await?;
+        let mut message_builder = MessageBuilder::<ClientMiner>::new(Parents::new(tips)?)
+            .with_nonce_provider(self.get_pow_provider().await, min_pow_score);
+        if let Some(payload) = payload {
+            message_builder = message_builder.with_payload(payload);
+        }
+        message_builder.finish().map_err(Error::MessageError)
+    }
+
     /// Reattach a message without checking if it should be reattached
     pub async fn reattach_unchecked(&self, message_id: &MessageId) -> Result<(MessageId, Message)> {
         // Get the Message object by the MessageID.
         let message = self.get_message_data(message_id).await?;
-        let reattach_message = {
-            #[cfg(feature = "wasm")]
-            {
-                let mut tips = self.get_tips().await?;
-                tips.sort_unstable_by_key(|a| a.pack_to_vec());
-                tips.dedup();
-                let mut message_builder = MessageBuilder::<ClientMiner>::new(Parents::new(tips)?);
-                if let Some(p) = message.payload().to_owned() {
-                    message_builder = message_builder.with_payload(p.clone())
-                }
-                message_builder.finish().map_err(Error::MessageError)?
-            }
-            #[cfg(not(feature = "wasm"))]
-            {
-                finish_pow(self, message.payload().cloned()).await?
-            }
-        };
+        let reattach_message = self.finish_message_builder(None, message.payload().cloned()).await?;
 
         // Post the modified
-        let message_id = self.post_message(&reattach_message).await?;
+        let message_id = self.post_message_raw(&reattach_message).await?;
         // Get message if we use remote PoW, because the node will change parents and nonce
         let msg = match self.get_local_pow().await {
             true => reattach_message,
@@ -898,18 +1948,11 @@ impl Client {
     pub async fn promote_unchecked(&self, message_id: &MessageId) -> Result<(MessageId, Message)> {
         // Create a new message (zero value message) for which one tip would be the actual message
         let mut tips = self.get_tips().await?;
-        let min_pow_score = self.get_min_pow_score().await?;
         tips.push(*message_id);
-        // Sort tips/parents
-        tips.sort_unstable_by_key(|a| a.pack_to_vec());
-        tips.dedup();
 
-        let promote_message = MessageBuilder::<ClientMiner>::new(Parents::new(tips)?)
-            .with_nonce_provider(self.get_pow_provider().await, min_pow_score)
-            .finish()
-            .map_err(|_| Error::TransactionError)?;
+        let promote_message = self.finish_message_builder(Some(tips), None).await?;
 
-        let message_id = self.post_message(&promote_message).await?;
+        let message_id = self.post_message_raw(&promote_message).await?;
         // Get message if we use remote PoW, because the node will change parents and nonce
         let msg = match self.get_local_pow().await {
             true => promote_message,
@@ -5,6 +5,12 @@
 
 use crate::Result;
 
+use bee_message::{
+    address::Address,
+    output::{AliasId, FoundryId, NftId, OutputId},
+    payload::transaction::TransactionId,
+    MessageId,
+};
 use regex::RegexSet;
 
 use std::{collections::HashMap, sync::Arc, time::Duration};
@@ -33,6 +39,32 @@ pub enum MqttEvent {
     Disconnected,
 }
 
+/// The payload of a `messages/{messageId}/metadata` topic event, parsed out of [`TopicEvent::payload`].
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct MessageMetadataEvent {
+    /// The id of the message the metadata is about.
+    #[serde(rename = "messageId")]
+    pub message_id: String,
+    /// Whether (and how) the message has been included in the ledger.
+    #[serde(rename = "ledgerInclusionState")]
+    pub ledger_inclusion_state: Option<bee_rest_api::types::dtos::LedgerInclusionStateDto>,
+    /// Whether the message should be promoted.
+    #[serde(rename = "shouldPromote")]
+    pub should_promote: Option<bool>,
+    /// Whether the message should be reattached.
+    #[serde(rename = "shouldReattach")]
+    pub should_reattach: Option<bool>,
+}
+
+/// The payload of a `milestones/confirmed` topic event, parsed out of [`TopicEvent::payload`].
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct MilestoneEvent {
+    /// The milestone index.
+    pub index: u32,
+    /// The milestone timestamp.
+    pub timestamp: u64,
+}
+
 /// The MQTT broker options.
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, PartialEq)]
@@ -177,4 +209,104 @@ impl Topic {
     pub fn topic(&self) -> &str {
         &self.0
     }
+
+    /// The `milestones/latest` topic.
+    pub fn milestones_latest() -> Self {
+        Self("milestones/latest".to_string())
+    }
+
+    /// The `milestones/confirmed` topic.
+    pub fn milestones_confirmed() -> Self {
+        Self("milestones/confirmed".to_string())
+    }
+
+    /// The `messages` topic, for every newly received message.
+    pub fn messages() -> Self {
+        Self("messages".to_string())
+    }
+
+    /// The `messages/referenced` topic.
+    pub fn messages_referenced() -> Self {
+        Self("messages/referenced".to_string())
+    }
+
+    /// The `messages/{messageId}/metadata` topic for `message_id`.
+    pub fn messages_metadata(message_id: &MessageId) -> Self {
+        Self(format!("messages/0x{message_id}/metadata"))
+    }
+
+    /// The `messages/tagged-data/{tag}` topic for `tag`, hex-encoded as the node expects.
+    pub fn tagged_data(tag: &[u8]) -> Self {
+        Self(format!("messages/tagged-data/0x{}", hex::encode(tag)))
+    }
+
+    /// The `transactions/{transactionId}/included-message` topic for `transaction_id`.
+    pub fn transaction_included_message(transaction_id: &TransactionId) -> Self {
+        Self(format!("transactions/0x{transaction_id}/included-message"))
+    }
+
+    /// The `outputs/{outputId}` topic for `output_id`.
+    pub fn output_by_id(output_id: &OutputId) -> Self {
+        Self(format!("outputs/0x{output_id}"))
+    }
+
+    /// The `outputs/aliases/{aliasId}` topic for `alias_id`.
+    pub fn alias_outputs(alias_id: &AliasId) -> Self {
+        Self(format!("outputs/aliases/0x{alias_id}"))
+    }
+
+    /// The `outputs/nfts/{nftId}` topic for `nft_id`.
+    pub fn nft_outputs(nft_id: &NftId) -> Self {
+        Self(format!("outputs/nfts/0x{nft_id}"))
+    }
+
+    /// The `outputs/foundries/{foundryId}` topic for `foundry_id`.
+    pub fn foundry_outputs(foundry_id: &FoundryId) -> Self {
+        Self(format!("outputs/foundries/0x{foundry_id}"))
+    }
+
+    /// The `outputs/unlock/{kind}/{address}` topic for outputs unlockable by `address` (rendered in bech32 using
+    /// `hrp`) under unlock condition `kind`, or its `/spent` variant when `spent` is set.
+    pub fn outputs_by_unlock_address(kind: UnlockConditionKind, address: &Address, hrp: &str, spent: bool) -> Self {
+        let suffix = if spent { "/spent" } else { "" };
+        Self(format!(
+            "outputs/unlock/{}/{}{}",
+            kind.as_topic_str(),
+            address.to_bech32(hrp),
+            suffix
+        ))
+    }
+}
+
+/// The kind of unlock condition an [`Topic::outputs_by_unlock_address`] subscription filters on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnlockConditionKind {
+    /// Any unlock condition.
+    Any,
+    /// The address unlock condition.
+    Address,
+    /// The storage deposit return unlock condition.
+    StorageReturn,
+    /// The expiration unlock condition.
+    ExpirationReturn,
+    /// The state controller address unlock condition.
+    StateController,
+    /// The governor address unlock condition.
+    Governor,
+    /// The immutable alias address unlock condition.
+    ImmutableAlias,
+}
+
+impl UnlockConditionKind {
+    fn as_topic_str(self) -> &'static str {
+        match self {
+            Self::Any => "+",
+            Self::Address => "address",
+            Self::StorageReturn => "storage-return",
+            Self::ExpirationReturn => "expiration-return",
+            Self::StateController => "state-controller",
+            Self::Governor => "governor",
+            Self::ImmutableAlias => "immutable-alias",
+        }
+    }
 }
@@ -14,11 +14,45 @@ use bee_message::{
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
     keys::slip10::{Chain, Curve, Seed},
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
 };
 
 use super::{types::InputSigningData, GenerateAddressMetadata, SecretManager, SignMessageMetadata};
 use crate::{constants::HD_WALLET_TYPE, Client, Result};
 
+/// A BIP44 derivation path, with only `coin_type` and `account` hardened as mandated by the standard. Used for
+/// [`MnemonicSecretManager::sign_secp256k1_ecdsa`], where EVM/Shimmer-style accounts require non-hardened
+/// `change`/`address_index` segments that [`Chain::from_u32_hardened`] cannot express.
+#[derive(Debug, Clone, Copy)]
+pub struct Bip44 {
+    coin_type: u32,
+    account: u32,
+    change: u32,
+    address_index: u32,
+}
+
+impl Bip44 {
+    /// Creates a new BIP44 path for the given `coin_type`/`account`/`change`/`address_index`.
+    pub fn new(coin_type: u32, account: u32, change: u32, address_index: u32) -> Self {
+        Self {
+            coin_type,
+            account,
+            change,
+            address_index,
+        }
+    }
+
+    fn to_chain(self) -> Chain {
+        Chain::from_u32(vec![
+            (HD_WALLET_TYPE, true),
+            (self.coin_type, true),
+            (self.account, true),
+            (self.change, false),
+            (self.address_index, false),
+        ])
+    }
+}
+
 /// Secret manager that uses only a mnemonic.
 ///
 /// Computation are done in-memory. A mnemonic needs to be supplied upon the creation of [`MnemonicSecretManager`].
@@ -98,6 +132,90 @@ impl MnemonicSecretManager {
     pub fn try_from_hex_seed(hex: &str) -> Result<Self> {
         Ok(Self(Seed::from_bytes(&hex::decode(hex)?)))
     }
+
+    /// Signs `msg_hash` with the secp256k1 ECDSA key derived at `chain`, returning a recoverable signature and the
+    /// compressed public key it was produced with. This unlocks cross-chain use cases (e.g. signing EVM-style
+    /// payloads) from the same seed used for Ed25519 addresses elsewhere in this manager.
+    pub fn sign_secp256k1_ecdsa(
+        &self,
+        chain: Bip44,
+        msg_hash: &[u8; 32],
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature)> {
+        let private_key = self.0.derive(Curve::Secp256k1, &chain.to_chain())?.secret_key();
+        let public_key = private_key.public_key();
+        let signature = private_key.sign_recoverable(msg_hash)?;
+
+        Ok((public_key, signature))
+    }
+
+    /// The EVM-style address (last 20 bytes of the keccak256 hash of the uncompressed public key) for the
+    /// secp256k1 key derived at `chain`.
+    pub fn evm_address(&self, chain: Bip44) -> Result<EvmAddress> {
+        let private_key = self.0.derive(Curve::Secp256k1, &chain.to_chain())?.secret_key();
+        Ok(private_key.public_key().evm_address())
+    }
+
+    /// Searches the address-index space, starting at 0, for an Ed25519 address whose bech32 representation (under
+    /// `hrp`) has a data part beginning with `pattern`, and returns the first `(address_index, Address)` that matches.
+    ///
+    /// `pattern` is matched case-insensitively against the data part of the bech32 string, and a `+` in `pattern`
+    /// matches any single character there, letting a caller widen the search (e.g. `"qp+y"`). Gives up with
+    /// [`crate::Error::VanitySearchExhausted`] once `max_attempts` addresses have been tried without a match.
+    pub fn generate_address_with_prefix(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        internal: bool,
+        hrp: &str,
+        pattern: &str,
+        max_attempts: u32,
+    ) -> Result<(u32, Address)> {
+        for address_index in 0..max_attempts {
+            let chain = Chain::from_u32_hardened(vec![
+                HD_WALLET_TYPE,
+                coin_type,
+                account_index,
+                internal as u32,
+                address_index,
+            ]);
+
+            let public_key = self
+                .0
+                .derive(Curve::Ed25519, &chain)?
+                .secret_key()
+                .public_key()
+                .to_bytes();
+
+            let result = Blake2b256::digest(&public_key).try_into().map_err(|_e| {
+                crate::Error::Blake2b256Error("Hashing the public key while generating the address failed.")
+            })?;
+
+            let address = Address::Ed25519(Ed25519Address::new(result));
+
+            if matches_vanity_pattern(&address.to_bech32(hrp), pattern) {
+                return Ok((address_index, address));
+            }
+        }
+
+        Err(crate::Error::VanitySearchExhausted(max_attempts))
+    }
+}
+
+/// Whether bech32 string `address`'s data part (after the `hrp1` separator) begins with `pattern`, matching
+/// case-insensitively and treating `+` in `pattern` as a wildcard for any single character.
+fn matches_vanity_pattern(address: &str, pattern: &str) -> bool {
+    let data = match address.rsplit_once('1') {
+        Some((_, data)) => data,
+        None => return false,
+    };
+
+    if pattern.len() > data.len() {
+        return false;
+    }
+
+    data.chars()
+        .zip(pattern.chars())
+        .all(|(a, p)| p == '+' || a.eq_ignore_ascii_case(&p))
 }
 
 #[cfg(test)]
@@ -163,4 +281,52 @@ mod tests {
             "atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r".to_string()
         );
     }
+
+    #[test]
+    fn generate_address_with_prefix_finds_known_index() {
+        use crate::constants::IOTA_COIN_TYPE;
+
+        let mnemonic = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+        let secmngr = MnemonicSecretManager::try_from_mnemonic(mnemonic).unwrap();
+
+        // Index 0's bech32 address is known from the `address` test above.
+        let (address_index, address) = secmngr
+            .generate_address_with_prefix(IOTA_COIN_TYPE, 0, false, "atoi", "qpsz", 5)
+            .unwrap();
+
+        assert_eq!(address_index, 0);
+        assert_eq!(
+            address.to_bech32("atoi"),
+            "atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e".to_string()
+        );
+    }
+
+    #[test]
+    fn generate_address_with_prefix_exhausted() {
+        use crate::constants::IOTA_COIN_TYPE;
+
+        let mnemonic = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+        let secmngr = MnemonicSecretManager::try_from_mnemonic(mnemonic).unwrap();
+
+        assert!(matches!(
+            secmngr.generate_address_with_prefix(IOTA_COIN_TYPE, 0, false, "atoi", "zzzzzzzzzz", 3),
+            Err(crate::Error::VanitySearchExhausted(3))
+        ));
+    }
+
+    #[test]
+    fn vanity_pattern_matching() {
+        assert!(matches_vanity_pattern(
+            "atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e",
+            "qpsz"
+        ));
+        assert!(matches_vanity_pattern(
+            "atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e",
+            "QP+Z"
+        ));
+        assert!(!matches_vanity_pattern(
+            "atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e",
+            "zzzz"
+        ));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,132 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Constants and helpers shared between [`secret`](super::secret) and [`signer`](super::signer), which implement
+//! [`SecretManage`](crate::secret::SecretManage) and [`Signer`](crate::signing::Signer) respectively against the
+//! same underlying Stronghold vault and snapshot format.
+
+use std::io::Write;
+
+use age::{armor::ArmoredWriter, stream::Format, Encryptor};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    XChaCha20Poly1305, XNonce,
+};
+
+/// The salt used by wallet.rs / Firefly to derive the symmetric key for a v2 (pre-`age`) snapshot.
+pub(crate) const V2_KDF_SALT: &[u8] = b"wallet.rs";
+
+/// PBKDF2-HMAC-SHA512 iteration count used by the v2 format.
+pub(crate) const V2_KDF_ITERATIONS: u32 = 100;
+
+/// Default scrypt work factor (log2 N) used when re-encrypting a migrated snapshot under the v3 `age` container,
+/// unless the caller overrides it via `StrongholdAdapterBuilder::snapshot_work_factor`.
+pub(crate) const DEFAULT_SNAPSHOT_WORK_FACTOR: u8 = 15;
+
+/// A BIP44 derivation path, with only `coin_type` and `account` hardened as mandated by the standard. This allows
+/// deriving non-hardened `change`/`address_index` segments, which EVM/Shimmer-style secp256k1 accounts rely on and
+/// which `Chain::from_u32_hardened` cannot express.
+#[derive(Debug, Clone, Copy)]
+pub struct Bip44 {
+    /// The coin type, e.g. `60` for Ethereum. Always hardened.
+    pub coin_type: u32,
+    /// The account index. Always hardened.
+    pub account: u32,
+    /// The change index (`0` external, `1` internal). Not hardened.
+    pub change: u32,
+    /// The address index. Not hardened.
+    pub address_index: u32,
+}
+
+impl Bip44 {
+    /// Creates a new [`Bip44`] chain.
+    pub fn new(coin_type: u32, account: u32, change: u32, address_index: u32) -> Self {
+        Self {
+            coin_type,
+            account,
+            change,
+            address_index,
+        }
+    }
+
+    /// Converts this path into a Stronghold [`crypto05::keys::slip10::Chain`], hardening only the
+    /// purpose/coin_type/account segments.
+    pub(crate) fn to_stronghold_chain(self) -> crypto05::keys::slip10::Chain {
+        // `(index, hardened)` pairs; `Chain::from_u32` (unlike `from_u32_hardened`) honors the per-segment flag.
+        crypto05::keys::slip10::Chain::from_u32(vec![
+            (44, true),
+            (self.coin_type, true),
+            (self.account, true),
+            (self.change, false),
+            (self.address_index, false),
+        ])
+    }
+}
+
+/// Derive the v2 symmetric key from `password` via PBKDF2-HMAC-SHA512 with the fixed wallet.rs salt, then decrypt
+/// the snapshot with XChaCha20-Poly1305 (no associated data expected). Returns `(associated_data, plaintext)`.
+pub(crate) fn v2_decrypt(snapshot_bytes: &[u8], password: &str) -> crate::Result<(Vec<u8>, Vec<u8>)> {
+    const NONCE_LEN: usize = 24;
+
+    if snapshot_bytes.len() < NONCE_LEN {
+        return Err(crate::Error::StrongholdMigrationError(
+            "snapshot is too short to be a v2 container".to_string(),
+        ));
+    }
+    let (nonce_bytes, ciphertext) = snapshot_bytes.split_at(NONCE_LEN);
+
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<sha2::Sha512>(password.as_bytes(), V2_KDF_SALT, V2_KDF_ITERATIONS, &mut key);
+
+    let cipher = XChaCha20Poly1305::new((&key).into());
+    let plaintext = cipher
+        .decrypt(XNonce::from_slice(nonce_bytes), ciphertext)
+        .map_err(|_| crate::Error::StrongholdMigrationError("failed to decrypt v2 snapshot".to_string()))?;
+
+    // The v2 container never attaches associated data of its own.
+    Ok((Vec::new(), plaintext))
+}
+
+/// Re-encrypt `plaintext` under the v3 `age` passphrase scheme: stretch `password` into a file key with scrypt
+/// (work factor `2^work_factor`), then authenticate the payload with ChaCha20-Poly1305.
+pub(crate) fn age_encrypt(plaintext: &[u8], password: &str, work_factor: u8) -> crate::Result<Vec<u8>> {
+    let recipient = age::scrypt::Recipient::new(secrecy::Secret::new(password.to_string()));
+    // Allow a cheap work factor (e.g. `0`) only when the caller has explicitly asked for it, which callers should
+    // only do when `password` is already high-entropy (e.g. in tests).
+    let recipient = recipient.set_work_factor(work_factor);
+
+    let mut out = Vec::new();
+    {
+        let armored = ArmoredWriter::wrap_output(&mut out, Format::Binary)
+            .map_err(|e| crate::Error::StrongholdMigrationError(e.to_string()))?;
+        let mut writer = Encryptor::with_recipients(vec![Box::new(recipient)])
+            .ok_or_else(|| crate::Error::StrongholdMigrationError("no age recipients".to_string()))?
+            .wrap_output(armored)
+            .map_err(|e| crate::Error::StrongholdMigrationError(e.to_string()))?;
+        writer
+            .write_all(plaintext)
+            .map_err(|e| crate::Error::StrongholdMigrationError(e.to_string()))?;
+        writer
+            .finish()
+            .and_then(|armored| armored.finish())
+            .map_err(|e| crate::Error::StrongholdMigrationError(e.to_string()))?;
+    }
+
+    Ok(out)
+}
+
+/// Builds a minimal, valid v2 snapshot (empty record store), for `migrate_snapshot`/`migrate_snapshot_v2_to_v3`
+/// test fixtures in both [`secret`](super::secret) and [`signer`](super::signer).
+#[cfg(test)]
+pub(crate) fn v2_fixture_bytes() -> Vec<u8> {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<sha2::Sha512>("old-password".as_bytes(), V2_KDF_SALT, V2_KDF_ITERATIONS, &mut key);
+
+    let nonce_bytes = [0u8; 24];
+    let cipher = XChaCha20Poly1305::new((&key).into());
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(&nonce_bytes), &[][..])
+        .expect("encryption of empty fixture should not fail");
+
+    [nonce_bytes.to_vec(), ciphertext].concat()
+}
@@ -3,7 +3,7 @@
 
 //! The [SecretManage] implementation for [StrongholdAdapter].
 
-use std::ops::Range;
+use std::{collections::HashMap, ops::Range, path::Path};
 
 use async_trait::async_trait;
 use bee_block::{
@@ -13,12 +13,16 @@ use bee_block::{
 };
 use crypto::hashes::{blake2b::Blake2b256, Digest};
 use iota_stronghold::{
-    procedures::{self, Chain, KeyType, Slip10DeriveInput},
+    procedures::{self, Chain, Curve, KeyType, Slip10DeriveInput},
     Location,
 };
+use log::warn;
 
 use super::{
-    common::{DERIVE_OUTPUT_RECORD_PATH, PRIVATE_DATA_CLIENT_PATH, SECRET_VAULT_PATH, SEED_RECORD_PATH},
+    common::{
+        age_encrypt, v2_decrypt, Bip44, DEFAULT_SNAPSHOT_WORK_FACTOR, DERIVE_OUTPUT_RECORD_PATH,
+        PRIVATE_DATA_CLIENT_PATH, SECRET_VAULT_PATH, SEED_RECORD_PATH,
+    },
     StrongholdAdapter,
 };
 use crate::{
@@ -27,6 +31,28 @@ use crate::{
     Error, Result,
 };
 
+/// The Chrysalis-era client path under which accounts/addresses/seed were stored.
+const CHRYSALIS_CLIENT_PATH: &[u8] = b"iota-wallet-stronghold-client";
+
+/// Known Chrysalis store record paths, keyed by the name that will be returned from [`StrongholdAdapter::
+/// get_chrysalis_data`]. Chrysalis snapshots stored a derived BIP-39 seed (not the mnemonic itself), so the seed
+/// record is included here rather than being recovered through the BIP-39 path used for v3 snapshots.
+const CHRYSALIS_STORE_KEYS: &[&str] = &["seed", "accounts", "addresses"];
+
+/// The vault record path for the scratch SLIP-10 key of `address_index`, distinct per index so that a batched
+/// `execute_procedures` call deriving many addresses at once doesn't have later derive steps overwrite earlier ones
+/// before their public keys have been read.
+fn scratch_record_path(address_index: u32) -> Vec<u8> {
+    let mut record_path = DERIVE_OUTPUT_RECORD_PATH.to_vec();
+    record_path.extend_from_slice(&address_index.to_be_bytes());
+    record_path
+}
+
+/// A scratch [`Location`] for the derived SLIP-10 key of `address_index`. See [`scratch_record_path`].
+fn derive_scratch_location(address_index: u32) -> Location {
+    Location::generic(SECRET_VAULT_PATH, scratch_record_path(address_index))
+}
+
 #[async_trait]
 impl SecretManage for StrongholdAdapter {
     async fn generate_addresses(
@@ -39,28 +65,54 @@ impl SecretManage for StrongholdAdapter {
     ) -> Result<Vec<Address>> {
         // Stronghold arguments.
         let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
-        let derive_location = Location::generic(SECRET_VAULT_PATH, DERIVE_OUTPUT_RECORD_PATH);
 
-        // Addresses to return.
-        let mut addresses = Vec::new();
-
-        for address_index in address_indexes {
+        // Rather than round-tripping to Stronghold three times (derive, public key, discard) per address, chain a
+        // derive+public-key step per index into a single `execute_procedures` call. Each index gets its own scratch
+        // location under `DERIVE_OUTPUT_RECORD_PATH` so the chained derive outputs don't clobber one another before
+        // their public keys are read.
+        let mut procedures = Vec::new();
+        for address_index in address_indexes.clone() {
             let chain = Chain::from_u32_hardened(vec![44u32, coin_type, account_index, internal as u32, address_index]);
+            let derive_location = derive_scratch_location(address_index);
+
+            procedures.push(procedures::StrongholdProcedure::Slip10Derive(procedures::Slip10Derive {
+                chain,
+                input: seed_location.clone(),
+                output: derive_location.clone(),
+            }));
+            procedures.push(procedures::StrongholdProcedure::PublicKey(procedures::PublicKey {
+                ty: KeyType::Ed25519,
+                private_key: derive_location,
+            }));
+        }
 
-            // Derive a SLIP-10 private key in the vault.
-            self.slip10_derive(chain, seed_location.clone(), derive_location.clone())?;
-
-            // Get the Ed25519 public key from the derived SLIP-10 private key in the vault.
-            let public_key = self.ed25519_public_key(derive_location.clone())?;
-
-            // Hash the public key to get the address.
-            let hash = Blake2b256::digest(&public_key);
-
-            // Convert the hash into [Address].
-            let address = Address::Ed25519(Ed25519Address::new(hash.into()));
-
-            // Collect it.
-            addresses.push(address);
+        let client = self.stronghold.get_client(PRIVATE_DATA_CLIENT_PATH)?;
+        let outputs = client.execute_procedures(procedures)?;
+
+        // Every other output is a `PublicKey` result (the `Slip10Derive` ones carry no useful return value); hash
+        // each public key into an address in the same order the indexes were requested.
+        let addresses = outputs
+            .into_iter()
+            .skip(1)
+            .step_by(2)
+            .map(|output| {
+                let public_key: [u8; 32] = output
+                    .try_into()
+                    .map_err(|_| crate::Error::StrongholdProcedureError("unexpected public key length".to_string()))?;
+                let hash = Blake2b256::digest(&public_key);
+                Ok(Address::Ed25519(Ed25519Address::new(hash.into())))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // The public keys have been read out of the scratch locations above; delete them now instead of leaving a
+        // permanent vault record behind per derived address, which would otherwise accumulate by the thousands over
+        // normal gap-limit scanning. This is best-effort: the addresses below are already successfully derived, so a
+        // stray vault record left behind by a failed deletion shouldn't discard them.
+        let vault = client.vault(SECRET_VAULT_PATH);
+        for address_index in address_indexes {
+            if let Err(e) = vault.delete_secret(&scratch_record_path(address_index)) {
+                warn!("Failed to delete scratch SLIP-10 record for address index {address_index}: {e}");
+            }
         }
 
         Ok(addresses)
@@ -85,19 +137,21 @@ impl SecretManage for StrongholdAdapter {
         let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
         let derive_location = Location::generic(SECRET_VAULT_PATH, DERIVE_OUTPUT_RECORD_PATH);
 
-        // Stronghold asks for an older version of [Chain], so we have to perform a conversion here.
+        // Stronghold asks for an older version of [Chain], so we have to perform a conversion here. We honor each
+        // segment's own hardened flag instead of assuming the whole chain is hardened, so non-hardened BIP44
+        // change/address segments (used by secp256k1 accounts, see [`sign_secp256k1_ecdsa`]) survive the round-trip.
         let chain = {
-            let raw: Vec<u32> = input
+            let raw: Vec<(u32, bool)> = input
                 .chain
                 .as_ref()
                 .unwrap()
                 .segments()
                 .iter()
                 // XXX: "ser32(i)". RTFSC: [crypto::keys::slip10::Segment::from_u32()]
-                .map(|seg| u32::from_be_bytes(seg.bs()))
+                .map(|seg| (u32::from_be_bytes(seg.bs()), seg.is_hardened()))
                 .collect();
 
-            Chain::from_u32_hardened(raw)
+            Chain::from_u32(raw)
         };
 
         // Derive a SLIP-10 private key in the vault.
@@ -116,6 +170,30 @@ impl SecretManage for StrongholdAdapter {
 
         Ok(unlock)
     }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        chain: Bip44,
+        msg: &[u8],
+    ) -> Result<(procedures::Secp256k1EcdsaPublicKey, procedures::Secp256k1EcdsaRecoverableSignature)> {
+        // Same guard as `signature_unlock`: without the cached key, Stronghold procedures would still run, but
+        // signing on behalf of the user without one cached doesn't make sense.
+        if !self.is_key_available().await {
+            return Err(Error::StrongholdKeyCleared);
+        }
+
+        // Stronghold arguments.
+        let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
+        let derive_location = Location::generic(SECRET_VAULT_PATH, DERIVE_OUTPUT_RECORD_PATH);
+
+        // Derive a SLIP-10 private key on the secp256k1 curve, following the (possibly non-hardened) BIP44 chain.
+        self.slip10_derive_secp256k1(chain.to_stronghold_chain(), seed_location, derive_location.clone())?;
+
+        let public_key = self.secp256k1_public_key(derive_location.clone())?;
+        let signature = self.secp256k1_ecdsa_sign(derive_location, msg)?;
+
+        Ok((public_key, signature))
+    }
 }
 
 /// Private methods for the secret manager implementation.
@@ -165,6 +243,48 @@ impl StrongholdAdapter {
             })?)
     }
 
+    /// Execute [Procedure::Slip10Derive] on the secp256k1 curve, as opposed to [`Self::slip10_derive`] which is
+    /// hard-coded to Ed25519.
+    fn slip10_derive_secp256k1(&self, chain: Chain, input: Slip10DeriveInput, output: Location) -> Result<()> {
+        self.stronghold.get_client(PRIVATE_DATA_CLIENT_PATH)?.execute_procedure(
+            procedures::Slip10Derive {
+                curve: Curve::Secp256k1,
+                chain,
+                input,
+                output,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Execute [Procedure::PublicKey] in Stronghold to get the compressed secp256k1 public key from the SLIP-10
+    /// private key located at `private_key`.
+    fn secp256k1_public_key(&self, private_key: Location) -> Result<procedures::Secp256k1EcdsaPublicKey> {
+        Ok(self
+            .stronghold
+            .get_client(PRIVATE_DATA_CLIENT_PATH)?
+            .execute_procedure(procedures::PublicKey {
+                ty: KeyType::Secp256k1Ecdsa,
+                private_key,
+            })?)
+    }
+
+    /// Execute Stronghold's secp256k1 ECDSA signing procedure, returning the recoverable (r‖s‖v) signature.
+    fn secp256k1_ecdsa_sign(
+        &self,
+        private_key: Location,
+        msg: &[u8],
+    ) -> Result<procedures::Secp256k1EcdsaRecoverableSignature> {
+        Ok(self
+            .stronghold
+            .get_client(PRIVATE_DATA_CLIENT_PATH)?
+            .execute_procedure(procedures::Secp256k1EcdsaSign {
+                private_key,
+                msg: msg.to_vec(),
+            })?)
+    }
+
     /// Store a mnemonic into the Stronghold vault.
     pub async fn store_mnemonic(&mut self, mnemonic: String) -> Result<()> {
         // Stronghold arguments.
@@ -206,6 +326,137 @@ impl StrongholdAdapter {
 
         Ok(())
     }
+
+    /// Migrate a legacy wallet.rs/Firefly v2 Stronghold snapshot to the current v3 (`age`) container, so it can
+    /// afterwards be opened with [`StrongholdAdapter::read_stronghold_snapshot`]. A snapshot that's already v3 is
+    /// left untouched, so calling this twice on the same snapshot is a no-op rather than an error.
+    ///
+    /// `work_factor` is the scrypt work factor (log2 N) used to re-encrypt the migrated snapshot; pass `0` in tests
+    /// where the new password is already high-entropy and a cheap KDF is acceptable.
+    pub async fn migrate_snapshot_v2_to_v3(
+        path: impl AsRef<Path> + Send,
+        old_password: &str,
+        new_password: &str,
+        work_factor: Option<u8>,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let snapshot_bytes = tokio::fs::read(path).await?;
+
+        // A v3 snapshot starts with the `age` format's own header; nothing to do.
+        if age::Decryptor::new(&snapshot_bytes[..]).is_ok() {
+            return Ok(());
+        }
+
+        // Step 1: recover the plaintext record store with the v2 KDF + AEAD scheme.
+        let (associated_data, plaintext) = v2_decrypt(&snapshot_bytes, old_password)?;
+
+        // Step 2: a v2 snapshot never carries associated data; reject anything unexpected rather than silently
+        // dropping it, as it may be load-bearing for a variant of the format we don't understand.
+        if !associated_data.is_empty() {
+            return Err(crate::Error::StrongholdMigrationError(
+                "v2 snapshot carries unexpected associated data".to_string(),
+            ));
+        }
+
+        // Step 3: records are carried over byte-for-byte; only the container around them changes.
+        let records = plaintext;
+
+        // Step 4: re-encrypt under the v3 `age` passphrase scheme and write atomically.
+        let ciphertext = age_encrypt(&records, new_password, work_factor.unwrap_or(DEFAULT_SNAPSHOT_WORK_FACTOR))?;
+
+        let tmp_path = path.with_extension("stronghold.migrating");
+        tokio::fs::write(&tmp_path, ciphertext).await?;
+        tokio::fs::rename(&tmp_path, path).await?;
+
+        Ok(())
+    }
+
+    /// Read the raw Chrysalis client/store entries (account indexes, addresses, and the stored BIP-39 seed) out of
+    /// an already-loaded Chrysalis-format snapshot, keyed by their original store keys, for migration tooling to
+    /// reconstruct wallet state from.
+    ///
+    /// This does not attempt to recover a mnemonic: Chrysalis snapshots only ever stored the derived seed.
+    pub async fn get_chrysalis_data(&self) -> Result<HashMap<String, Vec<u8>>> {
+        let client = self.stronghold.get_client(CHRYSALIS_CLIENT_PATH)?;
+
+        let mut data = HashMap::new();
+        for key in CHRYSALIS_STORE_KEYS {
+            let location = Location::generic(SECRET_VAULT_PATH, key.as_bytes());
+            if client.record_exists(&location)? {
+                let record = client
+                    .store()
+                    .get(key.as_bytes())
+                    .map_err(|e| crate::Error::StrongholdProcedureError(e.to_string()))?
+                    .ok_or_else(|| crate::Error::StrongholdProcedureError(format!("missing Chrysalis record: {key}")))?;
+                data.insert((*key).to_string(), record);
+            }
+        }
+
+        if data.is_empty() {
+            return Err(crate::Error::StrongholdNotChrysalisFormat);
+        }
+
+        Ok(data)
+    }
+
+    /// Rotate the passphrase protecting the snapshot at `snapshot_path`: verify `old_password` still unlocks it,
+    /// then re-encrypt the vault under a freshly derived key from `new_password` and atomically overwrite the file.
+    pub async fn change_password(&mut self, old_password: &str, new_password: &str) -> Result<()> {
+        if !self.is_key_available().await {
+            return Err(Error::StrongholdKeyCleared);
+        }
+
+        // Confirm `old_password` still unlocks the loaded snapshot before touching anything on disk; re-deriving
+        // the wrong key here would otherwise only surface as a silent failure the next time it's reopened.
+        self.set_password(old_password).await;
+        self.read_stronghold_snapshot().await?;
+
+        // Swap in the freshly derived key and persist; `write_stronghold_snapshot` re-encrypts the whole vault
+        // under whatever key is currently cached and overwrites `snapshot_path` in place.
+        self.set_password(new_password).await;
+        self.write_stronghold_snapshot().await?;
+
+        Ok(())
+    }
+
+    /// Write an encrypted copy of the currently loaded snapshot to `dest_path`, protected by an arbitrary
+    /// `password` rather than the one the adapter was opened with, so it can be handed out as a portable backup
+    /// without re-importing the mnemonic on the other end.
+    ///
+    /// The adapter's own cached key and `snapshot_path` are restored to what they were before this call once the
+    /// export completes (or fails), regardless of outcome.
+    pub async fn export_snapshot(&mut self, dest_path: impl AsRef<Path> + Send, password: &str) -> Result<()> {
+        if !self.is_key_available().await {
+            return Err(Error::StrongholdKeyCleared);
+        }
+
+        let dest_path = dest_path.as_ref().to_path_buf();
+
+        // Writing on top of the live snapshot would truncate it mid-read the moment `write_stronghold_snapshot`
+        // below reopens `snapshot_path`; refuse outright rather than risk corrupting the loaded vault.
+        if let Some(snapshot_path) = &self.snapshot_path {
+            if let (Ok(dest_canonical), Ok(live_canonical)) =
+                (dest_path.canonicalize(), snapshot_path.canonicalize())
+            {
+                if dest_canonical == live_canonical {
+                    return Err(Error::StrongholdSnapshotExportToSamePath);
+                }
+            }
+        }
+
+        let previous_snapshot_path = self.snapshot_path.replace(dest_path);
+        let previous_key_provider = self.key_provider.clone();
+        self.set_password(password).await;
+
+        let write_result = self.write_stronghold_snapshot().await;
+
+        // Restore the adapter's original target and key regardless of outcome, so the live snapshot keeps
+        // pointing at itself and remains protected by the password it was opened with.
+        self.snapshot_path = previous_snapshot_path;
+        self.key_provider = previous_key_provider;
+
+        write_result
+    }
 }
 
 #[cfg(test)]
@@ -213,7 +464,7 @@ mod tests {
     use std::path::PathBuf;
 
     use super::*;
-    use crate::constants::IOTA_COIN_TYPE;
+    use crate::{constants::IOTA_COIN_TYPE, stronghold::common::v2_fixture_bytes};
 
     #[tokio::test]
     async fn test_address_generation() {
@@ -250,4 +501,132 @@ mod tests {
         // Remove garbage after test, but don't care about the result
         std::fs::remove_file(stronghold_path).unwrap_or(());
     }
+
+    #[tokio::test]
+    async fn test_batched_address_generation_matches_per_index() {
+        let stronghold_path = PathBuf::from("test_batched_address_generation.stronghold");
+        let mnemonic = String::from(
+            "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally",
+        );
+        let mut stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap")
+            .try_build(stronghold_path.clone())
+            .unwrap();
+
+        stronghold_adapter.store_mnemonic(mnemonic).await.unwrap();
+
+        // Derive a large range in one batched call...
+        let batched = stronghold_adapter
+            .generate_addresses(
+                IOTA_COIN_TYPE,
+                0,
+                0..1000,
+                false,
+                GenerateAddressMetadata { syncing: false },
+            )
+            .await
+            .unwrap();
+        assert_eq!(batched.len(), 1000);
+
+        // ...and confirm it agrees with deriving one address at a time.
+        for (index, expected) in batched.iter().enumerate().step_by(97) {
+            let single = stronghold_adapter
+                .generate_addresses(
+                    IOTA_COIN_TYPE,
+                    0,
+                    index as u32..index as u32 + 1,
+                    false,
+                    GenerateAddressMetadata { syncing: false },
+                )
+                .await
+                .unwrap();
+            assert_eq!(&single[0], expected);
+        }
+
+        std::fs::remove_file(stronghold_path).unwrap_or(());
+    }
+
+    #[tokio::test]
+    async fn test_change_password_and_export_snapshot() {
+        let stronghold_path = PathBuf::from("test_change_password_and_export_snapshot.stronghold");
+        let export_path = PathBuf::from("test_change_password_and_export_snapshot_export.stronghold");
+        let mnemonic = String::from(
+            "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally",
+        );
+
+        let mut stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap")
+            .try_build(stronghold_path.clone())
+            .unwrap();
+        stronghold_adapter.store_mnemonic(mnemonic).await.unwrap();
+
+        // A wrong `old_password` must not be allowed to rotate the passphrase.
+        assert!(stronghold_adapter.change_password("wrong-password", "new-password").await.is_err());
+
+        stronghold_adapter
+            .change_password("drowssap", "new-password")
+            .await
+            .unwrap();
+
+        // The old password no longer opens the snapshot; the new one does.
+        assert!(
+            StrongholdAdapter::builder()
+                .password("drowssap")
+                .try_build(stronghold_path.clone())
+                .unwrap()
+                .read_stronghold_snapshot()
+                .await
+                .is_err()
+        );
+        let mut reopened = StrongholdAdapter::builder()
+            .password("new-password")
+            .try_build(stronghold_path.clone())
+            .unwrap();
+        assert!(reopened.read_stronghold_snapshot().await.is_ok());
+
+        // Exporting on top of the live snapshot is refused rather than risking a mid-read truncation.
+        assert!(matches!(
+            reopened.export_snapshot(&stronghold_path, "backup-password").await,
+            Err(Error::StrongholdSnapshotExportToSamePath)
+        ));
+
+        reopened.export_snapshot(&export_path, "backup-password").await.unwrap();
+        assert!(export_path.exists());
+
+        // The live adapter must still be usable under its own password after the export completes.
+        assert!(reopened.is_key_available().await);
+        assert!(
+            StrongholdAdapter::builder()
+                .password("backup-password")
+                .try_build(export_path.clone())
+                .unwrap()
+                .read_stronghold_snapshot()
+                .await
+                .is_ok()
+        );
+
+        std::fs::remove_file(stronghold_path).unwrap_or(());
+        std::fs::remove_file(export_path).unwrap_or(());
+    }
+
+    #[tokio::test]
+    async fn test_migrate_snapshot_v2_to_v3() {
+        let snapshot_path = PathBuf::from("test_migrate_snapshot_v2_to_v3.stronghold");
+        // v2 container built at run time by `v2_fixture_bytes`: XChaCha20-Poly1305 ciphertext of an empty record
+        // store, encrypted with PBKDF2-HMAC-SHA512("old-password", "wallet.rs", 100) and an all-zero nonce.
+        std::fs::write(&snapshot_path, v2_fixture_bytes()).unwrap();
+
+        StrongholdAdapter::migrate_snapshot_v2_to_v3(&snapshot_path, "old-password", "new-password", Some(0))
+            .await
+            .unwrap();
+
+        // The migrated file should now be readable as a v3 snapshot.
+        let stronghold_adapter = StrongholdAdapter::builder()
+            .password("new-password")
+            .try_build(snapshot_path.clone())
+            .unwrap();
+        assert!(stronghold_adapter.read_stronghold_snapshot().await.is_ok());
+
+        std::fs::remove_file(snapshot_path).unwrap_or(());
+    }
 }
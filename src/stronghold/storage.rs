@@ -0,0 +1,120 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A pluggable backend for persisting and backing up Stronghold snapshot bytes, so [`StrongholdAdapter`] isn't
+//! hard-wired to the local filesystem.
+//!
+//! [`StrongholdAdapter`]: super::StrongholdAdapter
+
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use super::common::STRONGHOLD_FILENAME;
+use crate::Result;
+
+/// Where a [`StrongholdAdapter`](super::StrongholdAdapter) reads, writes, and backs up its snapshot bytes, as an
+/// opaque blob. Implement this against an S3-compatible object store (or anything else) to keep snapshots off the
+/// local disk entirely, and to make the adapter testable without touching it. [`LocalFilesystemStorage`] is the
+/// default, covering today's `tokio::fs`-based behavior.
+#[async_trait]
+pub trait SnapshotStorage: Send + Sync {
+    /// Reads the full snapshot blob.
+    async fn read(&self) -> Result<Vec<u8>>;
+
+    /// Overwrites the snapshot blob, atomically where the backend supports it.
+    async fn write(&self, bytes: &[u8]) -> Result<()>;
+
+    /// Copies the current snapshot to a backup location suffixed with `timestamp` (a Unix timestamp, as produced by
+    /// `signer_sync`), mirroring the `{filename}-backup-{timestamp}.stronghold` naming it has always used.
+    async fn backup(&self, timestamp: &str) -> Result<()>;
+
+    /// Whether a snapshot blob currently exists.
+    async fn exists(&self) -> Result<bool>;
+}
+
+/// The default [`SnapshotStorage`]: reads, writes, and backs up the snapshot as a plain file at
+/// `snapshot_dir.join(STRONGHOLD_FILENAME)` via `tokio::fs`, exactly as
+/// [`StrongholdAdapter`](super::StrongholdAdapter) has always behaved, where `snapshot_dir` is the directory
+/// `StrongholdAdapter`'s `snapshot_path` points at.
+#[derive(Debug, Clone)]
+pub struct LocalFilesystemStorage {
+    path: PathBuf,
+}
+
+impl LocalFilesystemStorage {
+    /// Stores the snapshot inside `snapshot_dir`, at `snapshot_dir.join(STRONGHOLD_FILENAME)`.
+    pub fn new(snapshot_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            path: snapshot_dir.into().join(STRONGHOLD_FILENAME),
+        }
+    }
+
+    fn backup_path(&self, timestamp: &str) -> PathBuf {
+        let file_name = self.path.file_name().unwrap_or_default().to_string_lossy();
+        self.path.with_file_name(format!("{file_name}-backup-{timestamp}.stronghold"))
+    }
+}
+
+#[async_trait]
+impl SnapshotStorage for LocalFilesystemStorage {
+    async fn read(&self) -> Result<Vec<u8>> {
+        Ok(tokio::fs::read(&self.path).await?)
+    }
+
+    async fn write(&self, bytes: &[u8]) -> Result<()> {
+        // Write to a scratch file first and rename into place, so a crash mid-write can't leave a half-written
+        // snapshot at `path`.
+        let tmp_path = self.path.with_extension("stronghold.writing");
+        tokio::fs::write(&tmp_path, bytes).await?;
+        tokio::fs::rename(&tmp_path, &self.path).await?;
+        Ok(())
+    }
+
+    async fn backup(&self, timestamp: &str) -> Result<()> {
+        tokio::fs::copy(&self.path, self.backup_path(timestamp)).await?;
+        Ok(())
+    }
+
+    async fn exists(&self) -> Result<bool> {
+        Ok(tokio::fs::try_exists(&self.path).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_read_exists_roundtrip() {
+        let snapshot_dir = PathBuf::from("test_storage_roundtrip");
+        std::fs::create_dir_all(&snapshot_dir).unwrap();
+        let storage = LocalFilesystemStorage::new(snapshot_dir.clone());
+
+        assert!(!storage.exists().await.unwrap());
+
+        storage.write(b"snapshot bytes").await.unwrap();
+
+        assert!(storage.exists().await.unwrap());
+        assert_eq!(storage.read().await.unwrap(), b"snapshot bytes");
+
+        std::fs::remove_dir_all(snapshot_dir).unwrap_or(());
+    }
+
+    #[tokio::test]
+    async fn test_backup_naming() {
+        let snapshot_dir = PathBuf::from("test_storage_backup");
+        std::fs::create_dir_all(&snapshot_dir).unwrap();
+        let storage = LocalFilesystemStorage::new(snapshot_dir.clone());
+        storage.write(b"snapshot bytes").await.unwrap();
+
+        storage.backup("1234567890").await.unwrap();
+
+        let backup_path = snapshot_dir.join(format!("{STRONGHOLD_FILENAME}-backup-1234567890.stronghold"));
+        assert!(backup_path.exists());
+
+        std::fs::remove_dir_all(snapshot_dir).unwrap_or(());
+    }
+}
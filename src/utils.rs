@@ -0,0 +1,116 @@
+// Copyright 2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Miscellaneous utility functions that don't belong on [`Client`](crate::Client) itself.
+
+use std::time::Duration;
+
+#[cfg(feature = "wasm")]
+use gloo_timers::future::TimeoutFuture;
+use serde::Deserialize;
+
+use crate::{Error, Result};
+
+/// How long the first retry waits after a rate-limited faucet request; each subsequent retry doubles it.
+const FAUCET_BACKOFF_INITIAL: Duration = Duration::from_secs(1);
+/// Number of retries attempted before giving up with [`Error::FaucetLimitReached`].
+const FAUCET_BACKOFF_MAX_RETRIES: u32 = 5;
+
+/// A faucet's response to a successful funding request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FaucetResponse {
+    /// The bech32 address the funds were sent to.
+    pub address: String,
+    /// The amount of tokens sent, in the faucet's configured denomination.
+    pub amount: u64,
+    /// Whether the faucet accepted the request, as opposed to deduplicating/ignoring it.
+    pub accepted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct FaucetErrorResponse {
+    error: FaucetErrorBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct FaucetErrorBody {
+    code: String,
+    message: String,
+}
+
+/// A client for a devnet/testnet faucet, with rate-limit-aware retry.
+///
+/// Unlike [`Client`](crate::Client), this talks to a single faucet endpoint rather than a pool of nodes, since
+/// faucets aren't part of node synchronization or quorum.
+#[derive(Debug, Clone)]
+pub struct FaucetClient {
+    url: String,
+    http_client: reqwest::Client,
+}
+
+impl FaucetClient {
+    /// Creates a [`FaucetClient`] for the faucet enqueue endpoint at `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Requests funds for `bech32_address`.
+    ///
+    /// While the faucet reports it's rate limiting the caller (HTTP 429), this retries with bounded exponential
+    /// backoff before giving up with [`Error::FaucetLimitReached`]; the same error is returned immediately if the
+    /// faucet's per-address withdrawal limit has already been reached.
+    pub async fn request_funds(&self, bech32_address: &str) -> Result<FaucetResponse> {
+        let mut backoff = FAUCET_BACKOFF_INITIAL;
+
+        for attempt in 0..=FAUCET_BACKOFF_MAX_RETRIES {
+            let response = self
+                .http_client
+                .post(&self.url)
+                .json(&serde_json::json!({ "address": bech32_address }))
+                .send()
+                .await?;
+
+            if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                if attempt == FAUCET_BACKOFF_MAX_RETRIES {
+                    return Err(Error::FaucetLimitReached(bech32_address.to_string()));
+                }
+                #[cfg(feature = "wasm")]
+                TimeoutFuture::new(backoff.as_millis() as u32).await;
+                #[cfg(not(feature = "wasm"))]
+                tokio::time::sleep(backoff).await;
+
+                backoff *= 2;
+                continue;
+            }
+
+            if response.status().is_success() {
+                return Ok(response.json().await?);
+            }
+
+            let error: FaucetErrorResponse = response.json().await?;
+            return if error.error.code == "withdrawalLimitExceeded" {
+                Err(Error::FaucetLimitReached(bech32_address.to_string()))
+            } else {
+                Err(Error::ResponseError(error.error.message))
+            };
+        }
+
+        Err(Error::FaucetLimitReached(bech32_address.to_string()))
+    }
+}
+
+/// Requests funds from the faucet at `url` for `bech32_address`.
+///
+/// Kept as a thin convenience wrapper around [`FaucetClient`] for one-off calls, such as in examples, where
+/// constructing a dedicated client isn't worth the ceremony.
+pub async fn request_funds_from_faucet(url: &str, bech32_address: &str) -> Result<String> {
+    let response = FaucetClient::new(url).request_funds(bech32_address).await?;
+
+    Ok(format!(
+        "{{\"address\":\"{}\",\"amount\":{},\"accepted\":{}}}",
+        response.address, response.amount, response.accepted
+    ))
+}